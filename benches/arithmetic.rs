@@ -0,0 +1,38 @@
+//! Times `Wheel64` addition and multiplication on normal values.
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use wheel::w64;
+
+const LEN: usize = 1 << 20;
+const ROUNDS: u32 = 20;
+
+fn main() {
+    let values: Vec<w64> = (1..=LEN).map(|i| w64::new(1.0 + 1.0 / i as f64)).collect();
+
+    let start = Instant::now();
+    let mut sum = w64::ZERO;
+    for _ in 0..ROUNDS {
+        for &x in black_box(&values) {
+            sum = sum + x;
+        }
+    }
+    report("add", start, black_box(sum));
+
+    let start = Instant::now();
+    let mut product = w64::ONE;
+    for _ in 0..ROUNDS {
+        for &x in black_box(&values) {
+            product = product * x * x.inv();
+        }
+    }
+    report("mul", start, black_box(product));
+}
+
+fn report(name: &str, start: Instant, result: w64) {
+    let ops = LEN as f64 * ROUNDS as f64;
+    let nanos = start.elapsed().as_nanos() as f64;
+    println!("{name}: {:.2} ns/op (result {result:?})", nanos / ops);
+}
@@ -0,0 +1,25 @@
+//! Error type for fallible operations.
+
+use core::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WheelError {
+    /// The input string is not in the expected format.
+    InvalidFormat,
+    /// The value does not fit in the underlying type.
+    Overflow,
+    /// The result is `BOTTOM`.
+    Undefined,
+}
+
+impl Display for WheelError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            WheelError::InvalidFormat => write!(f, "invalid format"),
+            WheelError::Overflow => write!(f, "overflow"),
+            WheelError::Undefined => write!(f, "undefined result"),
+        }
+    }
+}
+
+impl core::error::Error for WheelError {}
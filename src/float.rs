@@ -1,11 +1,14 @@
 //! This module provides a floating point number type that can represent
 //! Wheel algebraic structures.
 
-use crate::Wheel;
+use crate::{Sign, Wheel, WheelClass, WheelError};
 
 use core::ops::{Add, Sub, Mul, Div, Neg};
+use core::iter::{Sum, Product};
 use core::num::FpCategory;
-use core::fmt::{self, Display, Debug, Formatter};
+use core::fmt::{self, Display, Debug, Formatter, LowerExp, UpperExp};
+use core::hash::{Hash, Hasher};
+use core::cmp::Ordering;
 
 
 #[derive(Clone, Copy)]
@@ -24,10 +27,32 @@ enum FpWheelCategory {
     Normal,
 }
 
+/// Lossless tagged form of a float wheel: the category, plus the exact
+/// bit pattern for normal (including subnormal) values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaggedBits<B> {
+    Zero,
+    Infinity,
+    Bottom,
+    Normal(B),
+}
+
 trait WheelCategoryGetter {
     fn get_category(&self) -> FpWheelCategory;
 }
 
+impl From<FpWheelCategory> for WheelClass {
+    #[inline]
+    fn from(category: FpWheelCategory) -> Self {
+        match category {
+            FpWheelCategory::Zero => WheelClass::Zero,
+            FpWheelCategory::Infinity => WheelClass::Infinity,
+            FpWheelCategory::Bottom => WheelClass::Bottom,
+            FpWheelCategory::Normal => WheelClass::Normal,
+        }
+    }
+}
+
 impl WheelCategoryGetter for f32 {
     #[inline]
     fn get_category(&self) -> FpWheelCategory {
@@ -54,6 +79,16 @@ impl WheelCategoryGetter for f64 {
     }
 }
 
+/// Distance from a finite `x` to the nearest integer.
+fn distance_to_integer(x: f64) -> f64 {
+    // Every f64 of magnitude 2^52 or more is an integer.
+    if x.abs() >= 4503599627370496.0 {
+        return 0.0;
+    }
+    let fraction = (x - x as i64 as f64).abs();
+    fraction.min(1.0 - fraction)
+}
+
 
 // Implementations for Wheel32
 
@@ -64,10 +99,15 @@ impl Wheel32 {
     pub const INFINITY: Self = Wheel32(f32::INFINITY);
     pub const BOTTOM: Self = Wheel32(f32::NAN);
 
-    pub fn new(value: f32) -> Self {
+    pub const fn new(value: f32) -> Self {
         Wheel32(value)
     }
 
+    /// The underlying `f32`.
+    pub const fn inner(&self) -> f32 {
+        self.0
+    }
+
     fn eq(&self, other: Self) -> bool {
         let self_category = self.0.get_category();
         let other_category = other.0.get_category();
@@ -80,6 +120,17 @@ impl Wheel32 {
     }
 
     pub fn roughly_eq(&self, other: Self) -> bool {
+        self.roughly_eq_within(other, 0.0001)
+    }
+
+    /// Whether the value is normal and within `tol` of an integer.
+    /// `ZERO`, `INFINITY` and `BOTTOM` give `false`.
+    pub fn is_close_to_integer(&self, tol: f64) -> bool {
+        self.0.get_category() == FpWheelCategory::Normal && distance_to_integer(self.0 as f64) <= tol
+    }
+
+    /// Like `roughly_eq`, with an explicit absolute tolerance for normal values.
+    pub fn roughly_eq_within(&self, other: Self, epsilon: f32) -> bool {
         let self_category = self.0.get_category();
         let other_category = other.0.get_category();
         if self_category != other_category {
@@ -87,10 +138,18 @@ impl Wheel32 {
         } else if self_category != FpWheelCategory::Normal {
             return true;
         }
-        (self.0 - other.0) < 0.0001 && (self.0 - other.0) > -0.0001
+        (self.0 - other.0) < epsilon && (self.0 - other.0) > -epsilon
     }
 
     fn add(&self, other: Self) -> Self {
+        // Finite operands (including zeros) need no special handling.
+        if self.0.is_finite() && other.0.is_finite() {
+            return Wheel32(self.0 + other.0);
+        }
+        self.add_by_category(other)
+    }
+
+    fn add_by_category(&self, other: Self) -> Self {
         match (self.0.get_category(), other.0.get_category()) {
             (FpWheelCategory::Bottom, _) => Self::BOTTOM,
             (_, FpWheelCategory::Bottom) => Self::BOTTOM,
@@ -104,6 +163,13 @@ impl Wheel32 {
     }
 
     fn mul(&self, other: Self) -> Self {
+        if self.0.is_finite() && other.0.is_finite() {
+            return Wheel32(self.0 * other.0);
+        }
+        self.mul_by_category(other)
+    }
+
+    fn mul_by_category(&self, other: Self) -> Self {
         match (self.0.get_category(), other.0.get_category()) {
             (FpWheelCategory::Bottom, _) => Self::BOTTOM,
             (_, FpWheelCategory::Bottom) => Self::BOTTOM,
@@ -117,6 +183,24 @@ impl Wheel32 {
         }
     }
 
+    /// Same categories as `self + other.neg()`, but normal values are
+    /// subtracted directly.
+    fn sub(&self, other: Self) -> Self {
+        match (self.0.get_category(), other.0.get_category()) {
+            (FpWheelCategory::Normal, FpWheelCategory::Normal) => Wheel32(self.0 - other.0),
+            _ => self.add(other.neg()),
+        }
+    }
+
+    /// Same categories as `self * other.inv()`, but normal values are
+    /// divided directly, avoiding the extra rounding of the reciprocal.
+    fn div(&self, other: Self) -> Self {
+        match (self.0.get_category(), other.0.get_category()) {
+            (FpWheelCategory::Normal, FpWheelCategory::Normal) => Wheel32(self.0 / other.0),
+            _ => self.mul(other.inv()),
+        }
+    }
+
     fn neg(&self) -> Self {
        self.mul(Self::NEGATIVE_ONE)
     }
@@ -129,6 +213,208 @@ impl Wheel32 {
             FpWheelCategory::Normal => Wheel32(1.0 / self.0),
         }
     }
+
+    /// Integer power, by repeated squaring directly on the `f32` for normal
+    /// values, with a single reciprocal at the end for negative exponents.
+    /// The specials follow `Wheel::pow`: `x.powi(0)` is `ONE` for every `x`,
+    /// `ZERO.powi(-1)` is `INFINITY`, and `BOTTOM` otherwise stays `BOTTOM`.
+    pub fn powi(&self, exp: i32) -> Self {
+        if exp == 0 {
+            return Self::ONE;
+        }
+        if self.0.get_category() != FpWheelCategory::Normal {
+            return if exp < 0 { self.inv() } else { *self };
+        }
+        let power = |mut base: f32| {
+            let mut exp = exp.unsigned_abs();
+            let mut result = 1.0;
+            loop {
+                if exp & 1 == 1 {
+                    result *= base;
+                }
+                exp >>= 1;
+                if exp == 0 {
+                    return result;
+                }
+                base *= base;
+            }
+        };
+        if exp > 0 {
+            return Wheel32(power(self.0));
+        }
+        let magnitude = power(self.0);
+        if magnitude.is_finite() {
+            Wheel32(1.0 / magnitude)
+        } else {
+            // The positive power overflows, but its reciprocal may still be subnormal.
+            Wheel32(power(1.0 / self.0))
+        }
+    }
+
+    /// `1 / self` by Newton–Raphson, `y ← y (2 - x y)`, from a bit-trick seed,
+    /// without dividing. The seed is within about 12%, and each iteration roughly
+    /// doubles the correct bits. Specials, and values too close to the ends of
+    /// the exponent range for the seed, go through `inv`.
+    pub fn approx_recip(&self, iterations: u32) -> Self {
+        let x = self.0.abs();
+        if self.0.get_category() != FpWheelCategory::Normal || !(f32::MIN_POSITIVE * 4.0..=f32::MAX / 4.0).contains(&x) {
+            return self.inv();
+        }
+        let mut y = f32::from_bits(0x7ef3_11c7u32 - x.to_bits());
+        for _ in 0..iterations {
+            y = y * (2.0 - x * y);
+        }
+        Wheel32(if self.0 < 0.0 { -y } else { y })
+    }
+
+    /// The category and, for normal values, the exact bits.
+    pub fn to_tagged_bits(&self) -> TaggedBits<u32> {
+        match self.0.get_category() {
+            FpWheelCategory::Zero => TaggedBits::Zero,
+            FpWheelCategory::Infinity => TaggedBits::Infinity,
+            FpWheelCategory::Bottom => TaggedBits::Bottom,
+            FpWheelCategory::Normal => TaggedBits::Normal(self.0.to_bits()),
+        }
+    }
+
+    /// Inverse of `to_tagged_bits`. Reconstructs normal values bit-for-bit.
+    pub fn from_tagged_bits(tagged: TaggedBits<u32>) -> Self {
+        match tagged {
+            TaggedBits::Zero => Self::ZERO,
+            TaggedBits::Infinity => Self::INFINITY,
+            TaggedBits::Bottom => Self::BOTTOM,
+            TaggedBits::Normal(bits) => Wheel32(f32::from_bits(bits)),
+        }
+    }
+
+    /// Linear interpolation `self + (other - self) * t`. See `Wheel::lerp`.
+    pub fn lerp(&self, other: &Self, t: Self) -> Self {
+        self.add(other.add(self.neg()).mul(t))
+    }
+
+    /// A finite stand-in for `self`, for consumers that cannot take infinities or NaN.
+    /// This is lossy: `INFINITY` becomes `f32::MAX` and `BOTTOM` becomes `ZERO`.
+    pub fn clamp_to_finite(&self) -> Self {
+        self.try_clamp_to_finite().unwrap_or(Self::ZERO)
+    }
+
+    /// Like `clamp_to_finite`, but `BOTTOM` gives `None`.
+    pub fn try_clamp_to_finite(&self) -> Option<Self> {
+        match self.0.get_category() {
+            FpWheelCategory::Bottom => None,
+            FpWheelCategory::Infinity => Some(Wheel32(f32::MAX)),
+            _ => Some(*self),
+        }
+    }
+
+    /// The next representable value above `self`. `ZERO` steps to the smallest
+    /// positive subnormal, and the largest finite value to `INFINITY`.
+    /// Going on around the projective line, `INFINITY` steps to `f32::MIN`.
+    /// `BOTTOM` stays `BOTTOM`.
+    pub fn next_up(&self) -> Self {
+        match self.0.get_category() {
+            FpWheelCategory::Bottom => Self::BOTTOM,
+            FpWheelCategory::Infinity => Wheel32(f32::MIN),
+            _ => Wheel32(self.0.next_up()),
+        }
+    }
+
+    /// The next representable value below `self`, the reverse of `next_up`:
+    /// `INFINITY` steps to `f32::MAX` and `f32::MIN` to `INFINITY`.
+    pub fn next_down(&self) -> Self {
+        match self.0.get_category() {
+            FpWheelCategory::Bottom => Self::BOTTOM,
+            FpWheelCategory::Infinity => Wheel32(f32::MAX),
+            _ => Wheel32(self.0.next_down()),
+        }
+    }
+
+    /// Splits a normal value into a mantissa with magnitude in `[0.5, 1)` and an
+    /// exponent, so that `self = mantissa · 2^exponent`. Subnormals are normalized.
+    /// `ZERO`, `INFINITY` and `BOTTOM` give themselves with exponent 0.
+    pub fn frexp(&self) -> (Self, i32) {
+        if self.0.get_category() != FpWheelCategory::Normal {
+            return (*self, 0);
+        }
+        let bits = self.0.to_bits();
+        let biased = ((bits >> 23) & 0xff) as i32;
+        if biased == 0 {
+            // Subnormal: scale into the normal range first.
+            let (mantissa, exponent) = Wheel32(self.0 * 4294967296.0).frexp();
+            return (mantissa, exponent - 32);
+        }
+        let mantissa = f32::from_bits(bits & !(0xff << 23) | (0x7e << 23));
+        (Wheel32(mantissa), biased - 0x7e)
+    }
+
+    /// `self · 2^exp`, rounded once. Results beyond the finite range become
+    /// `INFINITY`, and results that underflow completely become `ZERO`.
+    /// `ZERO`, `INFINITY` and `BOTTOM` are unchanged.
+    pub fn ldexp(&self, exp: i32) -> Self {
+        if self.0.get_category() != FpWheelCategory::Normal {
+            return *self;
+        }
+        // Scale in steps that stay representable, as in musl's `scalbn`.
+        // Going down, each step stops 24 bits above the subnormal range,
+        // so the final multiplication is the only rounding.
+        let up = f32::from_bits(0xfe << 23); // 2^127
+        let down = f32::from_bits(25 << 23); // 2^(-126 + 24)
+        let mut x = self.0;
+        let mut n = exp;
+        if n > 127 {
+            x *= up;
+            n -= 127;
+            if n > 127 {
+                x *= up;
+                n = (n - 127).min(127);
+            }
+        } else if n < -126 {
+            x *= down;
+            n -= -126 + 24;
+            if n < -126 {
+                x *= down;
+                n = (n - (-126 + 24)).max(-126);
+            }
+        }
+        Wheel32(x * f32::from_bits(((n + 127) as u32) << 23))
+    }
+
+    /// Absolute value. `ZERO`, `INFINITY` and `BOTTOM` are unsigned and stay as they are.
+    pub fn abs(&self) -> Self {
+        match self.0.get_category() {
+            FpWheelCategory::Normal => Wheel32(self.0.abs()),
+            _ => *self,
+        }
+    }
+
+    /// The sign of `self`; `ZERO`, `INFINITY` and `BOTTOM` each get their own variant.
+    pub fn sign(&self) -> Sign {
+        match self.0.get_category() {
+            FpWheelCategory::Zero => Sign::Zero,
+            FpWheelCategory::Infinity => Sign::Infinite,
+            FpWheelCategory::Bottom => Sign::Bottom,
+            FpWheelCategory::Normal if self.0 < 0.0 => Sign::Negative,
+            FpWheelCategory::Normal => Sign::Positive,
+        }
+    }
+
+    /// The magnitude of `self` with the sign of `sign`.
+    /// `ZERO`, `INFINITY` and `BOTTOM` are unsigned, so they are returned unchanged,
+    /// and an unsigned `sign` (`ZERO` or `INFINITY`) leaves `self` unchanged.
+    /// A `BOTTOM` sign gives `BOTTOM`.
+    pub fn copysign(&self, sign: &Self) -> Self {
+        match (self.0.get_category(), sign.0.get_category()) {
+            (_, FpWheelCategory::Bottom) => Self::BOTTOM,
+            (FpWheelCategory::Normal, FpWheelCategory::Normal) => Wheel32(self.0.copysign(sign.0)),
+            _ => *self,
+        }
+    }
+
+    /// `|self - other|`. `INFINITY` against a finite value gives `INFINITY`;
+    /// `INFINITY` against `INFINITY`, or any `BOTTOM`, gives `BOTTOM`.
+    pub fn abs_sub(&self, other: &Self) -> Self {
+        self.add(other.neg()).abs()
+    }
 }
 
 impl Wheel for Wheel32 {
@@ -152,6 +438,38 @@ impl Wheel for Wheel32 {
     fn inv(&self) -> Self {
         self.inv()
     }
+
+    fn from_f64(value: f64) -> Self {
+        Wheel32(value as f32)
+    }
+
+    fn approx_recip(&self, iterations: u32) -> Self {
+        self.approx_recip(iterations)
+    }
+
+    fn is_close_to_integer(&self, tol: f64) -> bool {
+        self.is_close_to_integer(tol)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == 1.0
+    }
+
+    fn scale_by_pow2(&self, k: i32) -> Self {
+        self.ldexp(k)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.sub(*other)
+    }
+
+    fn div(&self, other: &Self) -> Self {
+        self.div(*other)
+    }
+
+    fn classify(&self) -> WheelClass {
+        self.0.get_category().into()
+    }
 }
 
 impl PartialEq for Wheel32 {
@@ -162,6 +480,28 @@ impl PartialEq for Wheel32 {
 
 impl Eq for Wheel32 {}
 
+/// Zero and normal values are ordered as real numbers.
+/// `INFINITY` and `BOTTOM` are only comparable with themselves.
+impl PartialOrd for Wheel32 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.0.get_category(), other.0.get_category()) {
+            (FpWheelCategory::Zero | FpWheelCategory::Normal, FpWheelCategory::Zero | FpWheelCategory::Normal) => {
+                self.0.partial_cmp(&other.0)
+            }
+            (a, b) if a == b => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+/// Consistent with `Eq`: every NaN hashes as `BOTTOM`, and signed zeros
+/// and infinities hash as the single `ZERO` and `INFINITY`.
+impl Hash for Wheel32 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_tagged_bits().hash(state);
+    }
+}
+
 impl Debug for Wheel32 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self.0.get_category() {
@@ -184,6 +524,24 @@ impl Display for Wheel32 {
     }
 }
 
+impl LowerExp for Wheel32 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0.get_category() {
+            FpWheelCategory::Normal => LowerExp::fmt(&self.0, f),
+            _ => Display::fmt(self, f),
+        }
+    }
+}
+
+impl UpperExp for Wheel32 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0.get_category() {
+            FpWheelCategory::Normal => UpperExp::fmt(&self.0, f),
+            _ => Display::fmt(self, f),
+        }
+    }
+}
+
 
 // Conversion from floating point real numbers
 
@@ -236,7 +594,7 @@ impl Sub for Wheel32 {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        self.add(other.neg())
+        Self::sub(&self, other)
     }
 }
 
@@ -244,7 +602,7 @@ impl Sub<&Wheel32> for Wheel32 {
     type Output = Wheel32;
 
     fn sub(self, other: &Wheel32) -> Wheel32 {
-        self.add(other.neg())
+        Wheel32::sub(&self, *other)
     }
 }
 
@@ -252,7 +610,7 @@ impl Sub<Wheel32> for &Wheel32 {
     type Output = Wheel32;
 
     fn sub(self, other: Wheel32) -> Wheel32 {
-        self.add(other.neg())
+        Wheel32::sub(self, other)
     }
 }
 
@@ -260,7 +618,7 @@ impl Sub<&Wheel32> for &Wheel32 {
     type Output = Wheel32;
 
     fn sub(self, other: &Wheel32) -> Wheel32 {
-        self.add(other.neg())
+        Wheel32::sub(self, *other)
     }
 }
 
@@ -304,7 +662,7 @@ impl Div for Wheel32 {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
-        self.mul(other.inv())
+        Self::div(&self, other)
     }
 }
 
@@ -312,7 +670,7 @@ impl Div<&Wheel32> for Wheel32 {
     type Output = Wheel32;
 
     fn div(self, other: &Wheel32) -> Wheel32 {
-        self.mul(other.inv())
+        Wheel32::div(&self, *other)
     }
 }
 
@@ -320,7 +678,7 @@ impl Div<Wheel32> for &Wheel32 {
     type Output = Wheel32;
 
     fn div(self, other: Wheel32) -> Wheel32 {
-        (*self).mul(other.inv())
+        Wheel32::div(self, other)
     }
 }
 
@@ -328,7 +686,7 @@ impl Div<&Wheel32> for &Wheel32 {
     type Output = Wheel32;
 
     fn div(self, other: &Wheel32) -> Wheel32 {
-        (*self).mul(other.inv())
+        Wheel32::div(self, *other)
     }
 }
 
@@ -351,6 +709,33 @@ impl Neg for &Wheel32 {
 }
 
 
+// Iterator reductions
+
+impl Sum for Wheel32 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc.add(x))
+    }
+}
+
+impl<'a> Sum<&'a Wheel32> for Wheel32 {
+    fn sum<I: Iterator<Item = &'a Wheel32>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Wheel32 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc.mul(x))
+    }
+}
+
+impl<'a> Product<&'a Wheel32> for Wheel32 {
+    fn product<I: Iterator<Item = &'a Wheel32>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+
 // Implementations for Wheel64
 
 impl Wheel64 {
@@ -360,10 +745,27 @@ impl Wheel64 {
     pub const INFINITY: Self = Wheel64(f64::INFINITY);
     pub const BOTTOM: Self = Wheel64(f64::NAN);
 
-    pub fn new(value: f64) -> Self {
+    pub const fn new(value: f64) -> Self {
         Wheel64(value)
     }
 
+    /// The underlying `f64`.
+    pub const fn inner(&self) -> f64 {
+        self.0
+    }
+
+    /// Whether the value is subnormal. Subnormals still classify as
+    /// `WheelClass::Normal`; this only tells gradual underflow apart.
+    pub fn is_subnormal(&self) -> bool {
+        self.0.classify() == FpCategory::Subnormal
+    }
+
+    /// Whether the value is normal in the IEEE sense: `WheelClass::Normal`
+    /// without the subnormals.
+    pub fn is_normal_strict(&self) -> bool {
+        self.0.is_normal()
+    }
+
     fn eq(&self, other: Self) -> bool {
         let self_category = self.0.get_category();
         let other_category = other.0.get_category();
@@ -376,6 +778,17 @@ impl Wheel64 {
     }
 
     pub fn roughly_eq(&self, other: Self) -> bool {
+        self.roughly_eq_within(other, 0.0000001)
+    }
+
+    /// Whether the value is normal and within `tol` of an integer.
+    /// `ZERO`, `INFINITY` and `BOTTOM` give `false`.
+    pub fn is_close_to_integer(&self, tol: f64) -> bool {
+        self.0.get_category() == FpWheelCategory::Normal && distance_to_integer(self.0) <= tol
+    }
+
+    /// Like `roughly_eq`, with an explicit absolute tolerance for normal values.
+    pub fn roughly_eq_within(&self, other: Self, epsilon: f64) -> bool {
         let self_category = self.0.get_category();
         let other_category = other.0.get_category();
         if self_category != other_category {
@@ -383,10 +796,18 @@ impl Wheel64 {
         } else if self_category != FpWheelCategory::Normal {
             return true;
         }
-        (self.0 - other.0) < 0.0000001 && (self.0 - other.0) > -0.0000001
+        (self.0 - other.0) < epsilon && (self.0 - other.0) > -epsilon
     }
 
     fn add(&self, other: Self) -> Self {
+        // Finite operands (including zeros) need no special handling.
+        if self.0.is_finite() && other.0.is_finite() {
+            return Wheel64(self.0 + other.0);
+        }
+        self.add_by_category(other)
+    }
+
+    fn add_by_category(&self, other: Self) -> Self {
         match (self.0.get_category(), other.0.get_category()) {
             (FpWheelCategory::Bottom, _) => Self::BOTTOM,
             (_, FpWheelCategory::Bottom) => Self::BOTTOM,
@@ -400,6 +821,13 @@ impl Wheel64 {
     }
 
     fn mul(&self, other: Self) -> Self {
+        if self.0.is_finite() && other.0.is_finite() {
+            return Wheel64(self.0 * other.0);
+        }
+        self.mul_by_category(other)
+    }
+
+    fn mul_by_category(&self, other: Self) -> Self {
         match (self.0.get_category(), other.0.get_category()) {
             (FpWheelCategory::Bottom, _) => Self::BOTTOM,
             (_, FpWheelCategory::Bottom) => Self::BOTTOM,
@@ -413,6 +841,24 @@ impl Wheel64 {
         }
     }
 
+    /// Same categories as `self + other.neg()`, but normal values are
+    /// subtracted directly.
+    fn sub(&self, other: Self) -> Self {
+        match (self.0.get_category(), other.0.get_category()) {
+            (FpWheelCategory::Normal, FpWheelCategory::Normal) => Wheel64(self.0 - other.0),
+            _ => self.add(other.neg()),
+        }
+    }
+
+    /// Same categories as `self * other.inv()`, but normal values are
+    /// divided directly, avoiding the extra rounding of the reciprocal.
+    fn div(&self, other: Self) -> Self {
+        match (self.0.get_category(), other.0.get_category()) {
+            (FpWheelCategory::Normal, FpWheelCategory::Normal) => Wheel64(self.0 / other.0),
+            _ => self.mul(other.inv()),
+        }
+    }
+
     fn neg(&self) -> Self {
        self.mul(Self::NEGATIVE_ONE)
     }
@@ -425,90 +871,610 @@ impl Wheel64 {
             FpWheelCategory::Normal => Wheel64(1.0 / self.0),
         }
     }
-}
-
-impl Wheel for Wheel64 {
-    const ZERO: Self = Self::ZERO;
-    const ONE: Self = Self::ONE;
-    const INFINITY: Self = Self::INFINITY;
-    const BOTTOM: Self = Self::BOTTOM;
 
-    fn add(&self, other: &Self) -> Self {
-        self.add(*other)
+    /// Integer power, by repeated squaring directly on the `f64` for normal
+    /// values, with a single reciprocal at the end for negative exponents.
+    /// The specials follow `Wheel::pow`: `x.powi(0)` is `ONE` for every `x`,
+    /// `ZERO.powi(-1)` is `INFINITY`, and `BOTTOM` otherwise stays `BOTTOM`.
+    pub fn powi(&self, exp: i32) -> Self {
+        if exp == 0 {
+            return Self::ONE;
+        }
+        if self.0.get_category() != FpWheelCategory::Normal {
+            return if exp < 0 { self.inv() } else { *self };
+        }
+        let power = |mut base: f64| {
+            let mut exp = exp.unsigned_abs();
+            let mut result = 1.0;
+            loop {
+                if exp & 1 == 1 {
+                    result *= base;
+                }
+                exp >>= 1;
+                if exp == 0 {
+                    return result;
+                }
+                base *= base;
+            }
+        };
+        if exp > 0 {
+            return Wheel64(power(self.0));
+        }
+        let magnitude = power(self.0);
+        if magnitude.is_finite() {
+            Wheel64(1.0 / magnitude)
+        } else {
+            // The positive power overflows, but its reciprocal may still be subnormal.
+            Wheel64(power(1.0 / self.0))
+        }
     }
 
-    fn neg(&self) -> Self {
-        self.neg()
+    /// `inv`, and whether it was exact. A finite reciprocal is exact only
+    /// for powers of two whose reciprocal does not overflow; the specials'
+    /// reciprocals are always exact.
+    pub fn recip_checked(&self) -> (Self, bool) {
+        let inverse = self.inv();
+        if self.0.get_category() != FpWheelCategory::Normal {
+            return (inverse, true);
+        }
+        let magnitude = self.0.abs().to_bits();
+        let power_of_two = if self.0.is_normal() {
+            magnitude & ((1 << 52) - 1) == 0
+        } else {
+            magnitude.is_power_of_two()
+        };
+        (inverse, power_of_two && inverse.0.is_finite())
+    }
+
+    /// `1 / self` by Newton–Raphson, `y ← y (2 - x y)`, from a bit-trick seed,
+    /// without dividing. The seed is within about 12%, and each iteration roughly
+    /// doubles the correct bits. Specials, and values too close to the ends of
+    /// the exponent range for the seed, go through `inv`.
+    pub fn approx_recip(&self, iterations: u32) -> Self {
+        let x = self.0.abs();
+        if self.0.get_category() != FpWheelCategory::Normal || !(f64::MIN_POSITIVE * 4.0..=f64::MAX / 4.0).contains(&x) {
+            return self.inv();
+        }
+        let mut y = f64::from_bits(0x7fde_6238_22fc_16e6u64 - x.to_bits());
+        for _ in 0..iterations {
+            y = y * (2.0 - x * y);
+        }
+        Wheel64(if self.0 < 0.0 { -y } else { y })
     }
 
-    fn mul(&self, other: &Self) -> Self {
-        self.mul(*other)
+    /// The category and, for normal values, the exact bits.
+    pub fn to_tagged_bits(&self) -> TaggedBits<u64> {
+        match self.0.get_category() {
+            FpWheelCategory::Zero => TaggedBits::Zero,
+            FpWheelCategory::Infinity => TaggedBits::Infinity,
+            FpWheelCategory::Bottom => TaggedBits::Bottom,
+            FpWheelCategory::Normal => TaggedBits::Normal(self.0.to_bits()),
+        }
     }
 
-    fn inv(&self) -> Self {
-        self.inv()
+    /// Inverse of `to_tagged_bits`. Reconstructs normal values bit-for-bit.
+    pub fn from_tagged_bits(tagged: TaggedBits<u64>) -> Self {
+        match tagged {
+            TaggedBits::Zero => Self::ZERO,
+            TaggedBits::Infinity => Self::INFINITY,
+            TaggedBits::Bottom => Self::BOTTOM,
+            TaggedBits::Normal(bits) => Wheel64(f64::from_bits(bits)),
+        }
     }
-}
 
-impl PartialEq for Wheel64 {
-    fn eq(&self, other: &Self) -> bool {
-        self.eq(*other)
+    /// A category code and value for crossing a C ABI: `(0, 0.0)` for `ZERO`,
+    /// `(1, value)` for normal values, `(2, 0.0)` for `INFINITY` and
+    /// `(3, 0.0)` for `BOTTOM`. The codes are those of `WheelClass::code`.
+    pub fn decompose(&self) -> (u8, f64) {
+        let class = WheelClass::from(self.0.get_category());
+        let value = if class == WheelClass::Normal { self.0 } else { 0.0 };
+        (class.code(), value)
     }
-}
 
-impl Eq for Wheel64 {}
+    /// Inverse of `decompose`. `value` is only read for code 1, where it is
+    /// wrapped as with `new`. An unknown code gives `BOTTOM`.
+    pub fn compose(code: u8, value: f64) -> Self {
+        match WheelClass::from_code(code) {
+            Some(WheelClass::Zero) => Self::ZERO,
+            Some(WheelClass::Normal) => Wheel64(value),
+            Some(WheelClass::Infinity) => Self::INFINITY,
+            Some(WheelClass::Bottom) | None => Self::BOTTOM,
+        }
+    }
 
-impl Debug for Wheel64 {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self.0.get_category() {
-            FpWheelCategory::Zero => write!(f, "Wheel64::ZERO"),
-            FpWheelCategory::Infinity => write!(f, "Wheel64::INFINITY"),
-            FpWheelCategory::Bottom => write!(f, "Wheel64::BOTTOM"),
-            FpWheelCategory::Normal => write!(f, "Wheel64({})", self.0),
+    /// A fixed 9-byte encoding: a tag byte (`WheelClass::code`, as in
+    /// `decompose`) followed by the little-endian `f64` bits, which are all
+    /// zero unless the value is normal. Normal values round-trip bit-for-bit.
+    pub fn to_le_bytes(&self) -> [u8; 9] {
+        let (class, bits) = match self.to_tagged_bits() {
+            TaggedBits::Zero => (WheelClass::Zero, 0),
+            TaggedBits::Infinity => (WheelClass::Infinity, 0),
+            TaggedBits::Bottom => (WheelClass::Bottom, 0),
+            TaggedBits::Normal(bits) => (WheelClass::Normal, bits),
+        };
+        let mut bytes = [0; 9];
+        bytes[0] = class.code();
+        bytes[1..].copy_from_slice(&bits.to_le_bytes());
+        bytes
+    }
+
+    /// Inverse of `to_le_bytes`. Malformed input decodes as `BOTTOM`;
+    /// use `try_from_le_bytes` to detect it.
+    pub fn from_le_bytes(bytes: [u8; 9]) -> Self {
+        Self::try_from_le_bytes(bytes).unwrap_or(Self::BOTTOM)
+    }
+
+    /// Inverse of `to_le_bytes`. Fails with `InvalidFormat` on an unknown tag,
+    /// nonzero payload bytes for a special, or a payload under the normal tag
+    /// that is not a nonzero finite `f64`.
+    pub fn try_from_le_bytes(bytes: [u8; 9]) -> Result<Self, WheelError> {
+        let mut payload = [0; 8];
+        payload.copy_from_slice(&bytes[1..]);
+        let bits = u64::from_le_bytes(payload);
+        let tagged = match (WheelClass::from_code(bytes[0]), bits) {
+            (Some(WheelClass::Zero), 0) => TaggedBits::Zero,
+            (Some(WheelClass::Infinity), 0) => TaggedBits::Infinity,
+            (Some(WheelClass::Bottom), 0) => TaggedBits::Bottom,
+            (Some(WheelClass::Normal), bits) if f64::from_bits(bits).get_category() == FpWheelCategory::Normal => {
+                TaggedBits::Normal(bits)
+            }
+            _ => return Err(WheelError::InvalidFormat),
+        };
+        Ok(Self::from_tagged_bits(tagged))
+    }
+
+    /// A total order: the reals in order, then `INFINITY`, then `BOTTOM`.
+    /// Signed zeros are equal, as are all NaNs.
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        let rank = |x: &Self| match x.0.get_category() {
+            FpWheelCategory::Zero | FpWheelCategory::Normal => 0,
+            FpWheelCategory::Infinity => 1,
+            FpWheelCategory::Bottom => 2,
+        };
+        match (rank(self), rank(other)) {
+            (0, 0) => self.0.partial_cmp(&other.0).unwrap(),
+            (a, b) => a.cmp(&b),
         }
     }
-}
 
-impl Display for Wheel64 {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    /// A canonical `f64` for wrappers such as `ordered_float::OrderedFloat`:
+    /// `ZERO` is `0.0`, `INFINITY` is `f64::INFINITY`, `BOTTOM` is a positive NaN,
+    /// and normal values are unchanged. Under `f64::total_cmp` these sort
+    /// the same way as `Wheel64::total_cmp`.
+    pub fn to_ordered_f64(&self) -> f64 {
         match self.0.get_category() {
-            FpWheelCategory::Zero => write!(f, "0"),
-            FpWheelCategory::Infinity => write!(f, "Inf"),
-            FpWheelCategory::Bottom => write!(f, "Bottom"),
-            FpWheelCategory::Normal => write!(f, "{}", self.0),
+            FpWheelCategory::Zero => 0.0,
+            FpWheelCategory::Infinity => f64::INFINITY,
+            FpWheelCategory::Bottom => f64::NAN,
+            FpWheelCategory::Normal => self.0,
         }
     }
-}
-
 
-// Conversion from floating point real numbers
-
-impl From<f64> for Wheel64 {
-    fn from(value: f64) -> Self {
+    /// Inverse of `to_ordered_f64`. The round trip gives an equal wheel value,
+    /// but not the same bits: `-0.0`, `-inf` and NaN payloads are canonicalized
+    /// on the way out. Any NaN comes back as `BOTTOM`.
+    pub fn from_ordered_f64(value: f64) -> Self {
         Wheel64(value)
     }
-}
-
 
-// Arithmetic operations
+    /// Linear interpolation `self + (other - self) * t`. See `Wheel::lerp`.
+    pub fn lerp(&self, other: &Self, t: Self) -> Self {
+        self.add(other.add(self.neg()).mul(t))
+    }
 
-// Add
+    /// A finite stand-in for `self`, for consumers that cannot take infinities or NaN.
+    /// This is lossy: `INFINITY` becomes `f64::MAX` and `BOTTOM` becomes `ZERO`.
+    pub fn clamp_to_finite(&self) -> Self {
+        self.try_clamp_to_finite().unwrap_or(Self::ZERO)
+    }
 
-impl Add for Wheel64 {
-    type Output = Self;
+    /// Like `clamp_to_finite`, but `BOTTOM` gives `None`.
+    pub fn try_clamp_to_finite(&self) -> Option<Self> {
+        match self.0.get_category() {
+            FpWheelCategory::Bottom => None,
+            FpWheelCategory::Infinity => Some(Wheel64(f64::MAX)),
+            _ => Some(*self),
+        }
+    }
 
-    fn add(self, other: Self) -> Self {
-        Self::add(&self, other)
+    /// The next representable value above `self`. `ZERO` steps to the smallest
+    /// positive subnormal, and the largest finite value to `INFINITY`.
+    /// Going on around the projective line, `INFINITY` steps to `f64::MIN`.
+    /// `BOTTOM` stays `BOTTOM`.
+    pub fn next_up(&self) -> Self {
+        match self.0.get_category() {
+            FpWheelCategory::Bottom => Self::BOTTOM,
+            FpWheelCategory::Infinity => Wheel64(f64::MIN),
+            _ => Wheel64(self.0.next_up()),
+        }
     }
-}
 
-impl Add<&Wheel64> for Wheel64 {
-    type Output = Wheel64;
+    /// The next representable value below `self`, the reverse of `next_up`:
+    /// `INFINITY` steps to `f64::MAX` and `f64::MIN` to `INFINITY`.
+    pub fn next_down(&self) -> Self {
+        match self.0.get_category() {
+            FpWheelCategory::Bottom => Self::BOTTOM,
+            FpWheelCategory::Infinity => Wheel64(f64::MAX),
+            _ => Wheel64(self.0.next_down()),
+        }
+    }
 
-    fn add(self, other: &Wheel64) -> Wheel64 {
-        self.add(*other)
+    /// Splits a normal value into a mantissa with magnitude in `[0.5, 1)` and an
+    /// exponent, so that `self = mantissa · 2^exponent`. Subnormals are normalized.
+    /// `ZERO`, `INFINITY` and `BOTTOM` give themselves with exponent 0.
+    pub fn frexp(&self) -> (Self, i32) {
+        if self.0.get_category() != FpWheelCategory::Normal {
+            return (*self, 0);
+        }
+        let bits = self.0.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        if biased == 0 {
+            // Subnormal: scale into the normal range first.
+            let (mantissa, exponent) = Wheel64(self.0 * 18446744073709551616.0).frexp();
+            return (mantissa, exponent - 64);
+        }
+        let mantissa = f64::from_bits(bits & !(0x7ff << 52) | (0x3fe << 52));
+        (Wheel64(mantissa), biased - 0x3fe)
     }
-}
+
+    /// `self · 2^exp`, rounded once. Results beyond the finite range become
+    /// `INFINITY`, and results that underflow completely become `ZERO`.
+    /// `ZERO`, `INFINITY` and `BOTTOM` are unchanged.
+    pub fn ldexp(&self, exp: i32) -> Self {
+        if self.0.get_category() != FpWheelCategory::Normal {
+            return *self;
+        }
+        // Scale in steps that stay representable, as in musl's `scalbn`.
+        // Going down, each step stops 53 bits above the subnormal range,
+        // so the final multiplication is the only rounding.
+        let up = f64::from_bits(0x7fe << 52); // 2^1023
+        let down = f64::from_bits(54 << 52); // 2^(-1022 + 53)
+        let mut x = self.0;
+        let mut n = exp;
+        if n > 1023 {
+            x *= up;
+            n -= 1023;
+            if n > 1023 {
+                x *= up;
+                n = (n - 1023).min(1023);
+            }
+        } else if n < -1022 {
+            x *= down;
+            n -= -1022 + 53;
+            if n < -1022 {
+                x *= down;
+                n = (n - (-1022 + 53)).max(-1022);
+            }
+        }
+        Wheel64(x * f64::from_bits(((n + 1023) as u64) << 52))
+    }
+
+    /// Absolute value. `ZERO`, `INFINITY` and `BOTTOM` are unsigned and stay as they are.
+    pub fn abs(&self) -> Self {
+        match self.0.get_category() {
+            FpWheelCategory::Normal => Wheel64(self.0.abs()),
+            _ => *self,
+        }
+    }
+
+    /// The sign of `self`; `ZERO`, `INFINITY` and `BOTTOM` each get their own variant.
+    pub fn sign(&self) -> Sign {
+        match self.0.get_category() {
+            FpWheelCategory::Zero => Sign::Zero,
+            FpWheelCategory::Infinity => Sign::Infinite,
+            FpWheelCategory::Bottom => Sign::Bottom,
+            FpWheelCategory::Normal if self.0 < 0.0 => Sign::Negative,
+            FpWheelCategory::Normal => Sign::Positive,
+        }
+    }
+
+    /// The sign as a wheel value for use in formulas: `ONE`, `NEGATIVE_ONE`
+    /// or `ZERO`, with `INFINITY` and `BOTTOM` returned as themselves.
+    pub fn signum_wheel(&self) -> Self {
+        match self.sign() {
+            Sign::Zero => Self::ZERO,
+            Sign::Positive => Self::ONE,
+            Sign::Negative => Self::NEGATIVE_ONE,
+            Sign::Infinite => Self::INFINITY,
+            Sign::Bottom => Self::BOTTOM,
+        }
+    }
+
+    /// The magnitude of `self` with the sign of `sign`.
+    /// `ZERO`, `INFINITY` and `BOTTOM` are unsigned, so they are returned unchanged,
+    /// and an unsigned `sign` (`ZERO` or `INFINITY`) leaves `self` unchanged.
+    /// A `BOTTOM` sign gives `BOTTOM`.
+    pub fn copysign(&self, sign: &Self) -> Self {
+        match (self.0.get_category(), sign.0.get_category()) {
+            (_, FpWheelCategory::Bottom) => Self::BOTTOM,
+            (FpWheelCategory::Normal, FpWheelCategory::Normal) => Wheel64(self.0.copysign(sign.0)),
+            _ => *self,
+        }
+    }
+
+    /// `|self - other|`. `INFINITY` against a finite value gives `INFINITY`;
+    /// `INFINITY` against `INFINITY`, or any `BOTTOM`, gives `BOTTOM`.
+    pub fn abs_sub(&self, other: &Self) -> Self {
+        self.add(other.neg()).abs()
+    }
+
+    /// `(self - reference) / reference`, signed. A zero `reference` gives
+    /// `INFINITY`, or `BOTTOM` when `self` is also zero.
+    pub fn relative_error_to(&self, reference: &Self) -> Self {
+        self.sub(*reference).div(*reference)
+    }
+
+    /// Arctangent. `INFINITY` (an unsigned slope) maps to `π/2`,
+    /// and `BOTTOM` propagates.
+    pub fn atan(&self) -> Self {
+        match self.0.get_category() {
+            FpWheelCategory::Bottom => Self::BOTTOM,
+            FpWheelCategory::Infinity => Wheel64(core::f64::consts::FRAC_PI_2),
+            FpWheelCategory::Zero => Self::ZERO,
+            FpWheelCategory::Normal => Wheel64(crate::math::atan(self.0)),
+        }
+    }
+
+    /// `1 / sqrt(self)`. `ZERO` maps to `INFINITY` and `INFINITY` to `ZERO`;
+    /// negative values and `BOTTOM` give `BOTTOM`.
+    pub fn recip_sqrt(&self) -> Self {
+        match self.0.get_category() {
+            FpWheelCategory::Bottom => Self::BOTTOM,
+            FpWheelCategory::Infinity => Self::ZERO,
+            FpWheelCategory::Zero => Self::INFINITY,
+            FpWheelCategory::Normal if self.0 < 0.0 => Self::BOTTOM,
+            FpWheelCategory::Normal => Wheel64(1.0 / crate::math::sqrt(self.0)),
+        }
+    }
+
+    /// Compensated (Neumaier) summation of the normal values.
+    /// `INFINITY` and `BOTTOM` in the slice propagate as with `add`.
+    pub fn kahan_sum(values: &[Wheel64]) -> Wheel64 {
+        Self::compensated_sum(values.iter().copied())
+    }
+
+    /// Sum of the elementwise products, up to the shorter length.
+    /// The products are added with `kahan_sum`'s compensation, so cancelling
+    /// terms lose less precision than with `Wheel::dot`'s default.
+    pub fn dot(a: &[Wheel64], b: &[Wheel64]) -> Wheel64 {
+        Self::compensated_sum(a.iter().zip(b).map(|(x, y)| x.mul(*y)))
+    }
+
+    /// `acc + Σ xs[i] · ws[i]` up to the shorter length, one fused multiply-add
+    /// per term while everything is finite (with `std`; without it each term
+    /// is rounded twice). `INFINITY` and `BOTTOM` follow the wheel rules.
+    pub fn fma_slice(acc: Wheel64, xs: &[Wheel64], ws: &[Wheel64]) -> Wheel64 {
+        xs.iter().zip(ws).fold(acc, |acc, (x, w)| {
+            if acc.0.is_finite() && x.0.is_finite() && w.0.is_finite() {
+                Wheel64(crate::math::mul_add(x.0, w.0, acc.0))
+            } else {
+                acc.add(x.mul(*w))
+            }
+        })
+    }
+
+    /// Sum of the elementwise products, up to the shorter length, computed
+    /// with error-free products and sums (Ogita, Rump and Oishi's `Dot2`), so
+    /// the result is as accurate as if done in twice the precision.
+    /// `INFINITY` and `BOTTOM` products propagate as with `add`.
+    pub fn sum_of_products(a: &[Wheel64], b: &[Wheel64]) -> Wheel64 {
+        Self::compensated_sum_of_products(a.iter().copied().zip(b.iter().copied()))
+    }
+
+    fn compensated_sum_of_products(pairs: impl Iterator<Item = (Wheel64, Wheel64)>) -> Wheel64 {
+        let mut special = Self::ZERO;
+        let mut sum = 0.0f64;
+        let mut compensation = 0.0f64;
+        for (x, y) in pairs {
+            let product = x.mul(y);
+            match product.0.get_category() {
+                FpWheelCategory::Normal => {
+                    let (p, product_error) = crate::math::two_product(x.0, y.0);
+                    let t = sum + p;
+                    let sum_error = if sum.abs() >= p.abs() { (sum - t) + p } else { (p - t) + sum };
+                    compensation += sum_error + product_error;
+                    sum = t;
+                }
+                FpWheelCategory::Zero => {}
+                _ => special = special.add(product),
+            }
+        }
+        // As in `compensated_sum`, an overflowed sum must not meet its `-inf` compensation.
+        if !sum.is_finite() {
+            return special.add(Wheel64(sum));
+        }
+        special.add(Wheel64(sum + compensation))
+    }
+
+    fn compensated_sum(values: impl Iterator<Item = Wheel64>) -> Wheel64 {
+        let mut special = Self::ZERO;
+        let mut sum = 0.0f64;
+        let mut compensation = 0.0f64;
+        for value in values {
+            match value.0.get_category() {
+                FpWheelCategory::Normal => {
+                    let t = sum + value.0;
+                    if sum.abs() >= value.0.abs() {
+                        compensation += (sum - t) + value.0;
+                    } else {
+                        compensation += (value.0 - t) + sum;
+                    }
+                    sum = t;
+                }
+                FpWheelCategory::Zero => {}
+                _ => special = special.add(value),
+            }
+        }
+        // Once the running sum overflows, the compensation is `-inf` and would make it NaN.
+        if !sum.is_finite() {
+            return special.add(Wheel64(sum));
+        }
+        special.add(Wheel64(sum + compensation))
+    }
+}
+
+impl Wheel for Wheel64 {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+    const INFINITY: Self = Self::INFINITY;
+    const BOTTOM: Self = Self::BOTTOM;
+
+    fn add(&self, other: &Self) -> Self {
+        self.add(*other)
+    }
+
+    fn neg(&self) -> Self {
+        self.neg()
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.mul(*other)
+    }
+
+    fn inv(&self) -> Self {
+        self.inv()
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Wheel64(value)
+    }
+
+    fn approx_recip(&self, iterations: u32) -> Self {
+        self.approx_recip(iterations)
+    }
+
+    fn is_close_to_integer(&self, tol: f64) -> bool {
+        self.is_close_to_integer(tol)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == 1.0
+    }
+
+    fn scale_by_pow2(&self, k: i32) -> Self {
+        self.ldexp(k)
+    }
+
+    fn dot(a: &[Self], b: &[Self]) -> Self {
+        Wheel64::dot(a, b)
+    }
+
+    fn sum_of_products(a: &[Self], b: &[Self]) -> Self {
+        Wheel64::sum_of_products(a, b)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.sub(*other)
+    }
+
+    fn div(&self, other: &Self) -> Self {
+        self.div(*other)
+    }
+
+    fn classify(&self) -> WheelClass {
+        self.0.get_category().into()
+    }
+}
+
+impl PartialEq for Wheel64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq(*other)
+    }
+}
+
+impl Eq for Wheel64 {}
+
+/// Zero and normal values are ordered as real numbers.
+/// `INFINITY` and `BOTTOM` are only comparable with themselves.
+impl PartialOrd for Wheel64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.0.get_category(), other.0.get_category()) {
+            (FpWheelCategory::Zero | FpWheelCategory::Normal, FpWheelCategory::Zero | FpWheelCategory::Normal) => {
+                self.0.partial_cmp(&other.0)
+            }
+            (a, b) if a == b => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+/// Consistent with `Eq`: every NaN hashes as `BOTTOM`, and signed zeros
+/// and infinities hash as the single `ZERO` and `INFINITY`.
+impl Hash for Wheel64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_tagged_bits().hash(state);
+    }
+}
+
+impl Debug for Wheel64 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0.get_category() {
+            FpWheelCategory::Zero => write!(f, "Wheel64::ZERO"),
+            FpWheelCategory::Infinity => write!(f, "Wheel64::INFINITY"),
+            FpWheelCategory::Bottom => write!(f, "Wheel64::BOTTOM"),
+            FpWheelCategory::Normal => write!(f, "Wheel64({})", self.0),
+        }
+    }
+}
+
+impl Display for Wheel64 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0.get_category() {
+            FpWheelCategory::Zero => write!(f, "0"),
+            FpWheelCategory::Infinity => write!(f, "Inf"),
+            FpWheelCategory::Bottom => write!(f, "Bottom"),
+            FpWheelCategory::Normal => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl LowerExp for Wheel64 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0.get_category() {
+            FpWheelCategory::Normal => LowerExp::fmt(&self.0, f),
+            _ => Display::fmt(self, f),
+        }
+    }
+}
+
+impl UpperExp for Wheel64 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0.get_category() {
+            FpWheelCategory::Normal => UpperExp::fmt(&self.0, f),
+            _ => Display::fmt(self, f),
+        }
+    }
+}
+
+
+// Conversion from floating point real numbers
+
+impl From<f64> for Wheel64 {
+    fn from(value: f64) -> Self {
+        Wheel64(value)
+    }
+}
+
+
+// Arithmetic operations
+
+// Add
+
+impl Add for Wheel64 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::add(&self, other)
+    }
+}
+
+impl Add<&Wheel64> for Wheel64 {
+    type Output = Wheel64;
+
+    fn add(self, other: &Wheel64) -> Wheel64 {
+        self.add(*other)
+    }
+}
 
 impl Add<Wheel64> for &Wheel64 {
     type Output = Wheel64;
@@ -532,7 +1498,7 @@ impl Sub for Wheel64 {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
-        self.add(other.neg())
+        Self::sub(&self, other)
     }
 }
 
@@ -540,7 +1506,7 @@ impl Sub<&Wheel64> for Wheel64 {
     type Output = Wheel64;
 
     fn sub(self, other: &Wheel64) -> Wheel64 {
-        self.add(other.neg())
+        Wheel64::sub(&self, *other)
     }
 }
 
@@ -548,7 +1514,7 @@ impl Sub<Wheel64> for &Wheel64 {
     type Output = Wheel64;
 
     fn sub(self, other: Wheel64) -> Wheel64 {
-        self.add(other.neg())
+        Wheel64::sub(self, other)
     }
 }
 
@@ -556,7 +1522,7 @@ impl Sub<&Wheel64> for &Wheel64 {
     type Output = Wheel64;
 
     fn sub(self, other: &Wheel64) -> Wheel64 {
-        self.add(other.neg())
+        Wheel64::sub(self, *other)
     }
 }
 
@@ -599,50 +1565,305 @@ impl Mul<&Wheel64> for &Wheel64 {
 impl Div for Wheel64 {
     type Output = Self;
 
-    fn div(self, other: Self) -> Self {
-        self.mul(other.inv())
+    fn div(self, other: Self) -> Self {
+        Self::div(&self, other)
+    }
+}
+
+impl Div<&Wheel64> for Wheel64 {
+    type Output = Wheel64;
+
+    fn div(self, other: &Wheel64) -> Wheel64 {
+        Wheel64::div(&self, *other)
+    }
+}
+
+impl Div<Wheel64> for &Wheel64 {
+    type Output = Wheel64;
+
+    fn div(self, other: Wheel64) -> Wheel64 {
+        Wheel64::div(self, other)
+    }
+}
+
+impl Div<&Wheel64> for &Wheel64 {
+    type Output = Wheel64;
+
+    fn div(self, other: &Wheel64) -> Wheel64 {
+        Wheel64::div(self, *other)
+    }
+}
+
+// Neg
+
+impl Neg for Wheel64 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::neg(&self)
+    }
+}
+
+impl Neg for &Wheel64 {
+    type Output = Wheel64;
+
+    fn neg(self) -> Wheel64 {
+        self.neg()
+    }
+}
+
+
+// Iterator reductions
+
+impl Sum for Wheel64 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc.add(x))
+    }
+}
+
+impl<'a> Sum<&'a Wheel64> for Wheel64 {
+    fn sum<I: Iterator<Item = &'a Wheel64>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Wheel64 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc.mul(x))
+    }
+}
+
+impl<'a> Product<&'a Wheel64> for Wheel64 {
+    fn product<I: Iterator<Item = &'a Wheel64>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+
+// Wheel64 with a type-level tolerance
+
+/// `Wheel64` whose `roughly_eq` uses the tolerance `2^-EPS_BITS`.
+/// `EPS_BITS` must be in `-1023..=1022`.
+/// Arithmetic is that of `Wheel64`; `Eq` stays exact (and category-based)
+/// so that it remains consistent with hashing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToleranceWheel64<const EPS_BITS: i32>(pub Wheel64);
+
+impl<const EPS_BITS: i32> ToleranceWheel64<EPS_BITS> {
+    pub fn new(value: f64) -> Self {
+        ToleranceWheel64(Wheel64(value))
+    }
+
+    /// `2^-EPS_BITS`.
+    pub fn epsilon() -> f64 {
+        const { assert!(-1023 <= EPS_BITS && EPS_BITS <= 1022) }
+        f64::from_bits(((1023 - EPS_BITS) as u64) << 52)
+    }
+
+    pub fn roughly_eq(&self, other: Self) -> bool {
+        self.0.roughly_eq_within(other.0, Self::epsilon())
+    }
+}
+
+impl<const EPS_BITS: i32> Wheel for ToleranceWheel64<EPS_BITS> {
+    const ZERO: Self = ToleranceWheel64(Wheel64::ZERO);
+    const ONE: Self = ToleranceWheel64(Wheel64::ONE);
+    const INFINITY: Self = ToleranceWheel64(Wheel64::INFINITY);
+    const BOTTOM: Self = ToleranceWheel64(Wheel64::BOTTOM);
+
+    fn add(&self, other: &Self) -> Self {
+        ToleranceWheel64(self.0.add(other.0))
+    }
+
+    fn neg(&self) -> Self {
+        ToleranceWheel64(self.0.neg())
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        ToleranceWheel64(self.0.mul(other.0))
+    }
+
+    fn inv(&self) -> Self {
+        ToleranceWheel64(self.0.inv())
+    }
+
+    fn from_f64(value: f64) -> Self {
+        ToleranceWheel64(Wheel64(value))
+    }
+
+    fn approx_recip(&self, iterations: u32) -> Self {
+        ToleranceWheel64(self.0.approx_recip(iterations))
+    }
+
+    fn is_close_to_integer(&self, tol: f64) -> bool {
+        self.0.is_close_to_integer(tol)
+    }
+
+    fn is_one(&self) -> bool {
+        Wheel::is_one(&self.0)
+    }
+
+    fn scale_by_pow2(&self, k: i32) -> Self {
+        ToleranceWheel64(self.0.ldexp(k))
+    }
+
+    fn dot(a: &[Self], b: &[Self]) -> Self {
+        ToleranceWheel64(Wheel64::compensated_sum(a.iter().zip(b).map(|(x, y)| x.0.mul(y.0))))
+    }
+
+    fn sum_of_products(a: &[Self], b: &[Self]) -> Self {
+        ToleranceWheel64(Wheel64::compensated_sum_of_products(a.iter().zip(b).map(|(x, y)| (x.0, y.0))))
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        ToleranceWheel64(self.0.sub(other.0))
+    }
+
+    fn div(&self, other: &Self) -> Self {
+        ToleranceWheel64(self.0.div(other.0))
+    }
+
+    fn pow(&self, exp: i32) -> Self {
+        ToleranceWheel64(Wheel::pow(&self.0, exp))
+    }
+
+    fn classify(&self) -> WheelClass {
+        Wheel::classify(&self.0)
+    }
+}
+
+impl<const EPS_BITS: i32> From<Wheel64> for ToleranceWheel64<EPS_BITS> {
+    fn from(value: Wheel64) -> Self {
+        ToleranceWheel64(value)
+    }
+}
+
+impl<const EPS_BITS: i32> Add for ToleranceWheel64<EPS_BITS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        ToleranceWheel64(self.0 + other.0)
+    }
+}
+
+impl<const EPS_BITS: i32> Sub for ToleranceWheel64<EPS_BITS> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        ToleranceWheel64(self.0 - other.0)
+    }
+}
+
+impl<const EPS_BITS: i32> Mul for ToleranceWheel64<EPS_BITS> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        ToleranceWheel64(self.0 * other.0)
+    }
+}
+
+impl<const EPS_BITS: i32> Div for ToleranceWheel64<EPS_BITS> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        ToleranceWheel64(self.0 / other.0)
+    }
+}
+
+impl<const EPS_BITS: i32> Neg for ToleranceWheel64<EPS_BITS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        ToleranceWheel64(-self.0)
+    }
+}
+
+
+// Wheel64 with IEEE-like bottom comparison
+
+/// `Wheel64` whose `BOTTOM` compares unequal to everything, itself included,
+/// like NaN. Since `==` is not reflexive this is only `PartialEq`, so it is
+/// not a `Wheel` and cannot be hashed; arithmetic is that of `Wheel64`.
+#[derive(Debug, Clone, Copy)]
+pub struct StrictWheel64(pub Wheel64);
+
+impl StrictWheel64 {
+    pub const ZERO: Self = StrictWheel64(Wheel64::ZERO);
+    pub const ONE: Self = StrictWheel64(Wheel64::ONE);
+    pub const INFINITY: Self = StrictWheel64(Wheel64::INFINITY);
+    pub const BOTTOM: Self = StrictWheel64(Wheel64::BOTTOM);
+
+    pub fn new(value: f64) -> Self {
+        StrictWheel64(Wheel64(value))
+    }
+
+    pub fn is_bottom(&self) -> bool {
+        self.0 == Wheel64::BOTTOM
+    }
+
+    pub fn inv(&self) -> Self {
+        StrictWheel64(self.0.inv())
+    }
+}
+
+impl PartialEq for StrictWheel64 {
+    fn eq(&self, other: &Self) -> bool {
+        !self.is_bottom() && !other.is_bottom() && self.0 == other.0
+    }
+}
+
+impl PartialOrd for StrictWheel64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_bottom() || other.is_bottom() {
+            return None;
+        }
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl From<Wheel64> for StrictWheel64 {
+    fn from(value: Wheel64) -> Self {
+        StrictWheel64(value)
     }
 }
 
-impl Div<&Wheel64> for Wheel64 {
-    type Output = Wheel64;
+impl Add for StrictWheel64 {
+    type Output = Self;
 
-    fn div(self, other: &Wheel64) -> Wheel64 {
-        self.mul(other.inv())
+    fn add(self, other: Self) -> Self {
+        StrictWheel64(self.0 + other.0)
     }
 }
 
-impl Div<Wheel64> for &Wheel64 {
-    type Output = Wheel64;
+impl Sub for StrictWheel64 {
+    type Output = Self;
 
-    fn div(self, other: Wheel64) -> Wheel64 {
-        (*self).mul(other.inv())
+    fn sub(self, other: Self) -> Self {
+        StrictWheel64(self.0 - other.0)
     }
 }
 
-impl Div<&Wheel64> for &Wheel64 {
-    type Output = Wheel64;
+impl Mul for StrictWheel64 {
+    type Output = Self;
 
-    fn div(self, other: &Wheel64) -> Wheel64 {
-        (*self).mul(other.inv())
+    fn mul(self, other: Self) -> Self {
+        StrictWheel64(self.0 * other.0)
     }
 }
 
-// Neg
-
-impl Neg for Wheel64 {
+impl Div for StrictWheel64 {
     type Output = Self;
 
-    fn neg(self) -> Self {
-        Self::neg(&self)
+    fn div(self, other: Self) -> Self {
+        StrictWheel64(self.0 / other.0)
     }
 }
 
-impl Neg for &Wheel64 {
-    type Output = Wheel64;
+impl Neg for StrictWheel64 {
+    type Output = Self;
 
-    fn neg(self) -> Wheel64 {
-        self.neg()
+    fn neg(self) -> Self {
+        StrictWheel64(-self.0)
     }
 }
 
@@ -659,7 +1880,11 @@ mod test {
     const BOTTOM: MyWheel = MyWheel::BOTTOM;
 
     fn assert_eq(a: MyWheel, b: MyWheel) {
-        assert!(a.roughly_eq(b));
+        assert!(a.roughly_eq(b), "{:?} != {:?}", a, b);
+    }
+
+    fn assert_eq_at<C: core::fmt::Debug>(a: MyWheel, b: MyWheel, at: C) {
+        assert!(a.roughly_eq(b), "{:?} != {:?} at {:?}", a, b, at);
     }
 
     #[inline]
@@ -689,8 +1914,9 @@ mod test {
 
     #[inline]
     fn any_numbers() -> [MyWheel; 9] {
+        let [zero, one, infinity, bottom] = MyWheel::specials();
         [
-            ZERO, ONE, INFINITY, BOTTOM,
+            zero, one, infinity, bottom,
             negative_one(), three(), negative_two(),
             half(), negative_quarter()
         ]
@@ -699,8 +1925,7 @@ mod test {
     #[test]
     fn inv_is_involution() {
         for &x in any_numbers().iter() {
-            println!("{:?} == {:?}", x.inv().inv(), x);
-            assert_eq(x.inv().inv(), x);
+            assert_eq_at(x.inv().inv(), x, x);
         }
     }
 
@@ -708,8 +1933,7 @@ mod test {
     fn inv_is_multicative() {
         for &x in any_numbers().iter() {
             for &y in any_numbers().iter() {
-                println!("{:?} == {:?}", (x * y).inv(), y.inv() * x.inv());
-                assert_eq((x * y).inv(), y.inv() * x.inv());
+                assert_eq_at((x * y).inv(), y.inv() * x.inv(), (x, y));
             }
         }
     }
@@ -720,8 +1944,7 @@ mod test {
         for &x in any_numbers().iter() {
             for &y in any_numbers().iter() {
                 for &z in any_numbers().iter() {
-                    println!("{:?} == {:?}", (x + y) * z + ZERO * z, x * z + y * z);
-                    assert_eq((x + y) * z + ZERO * z, x * z + y * z);
+                    assert_eq_at((x + y) * z + ZERO * z, x * z + y * z, (x, y, z));
                 }
             }
         }
@@ -733,8 +1956,7 @@ mod test {
         for &x in any_numbers().iter() {
             for &y in any_numbers().iter() {
                 for &z in any_numbers().iter() {
-                    println!("{:?} == {:?}", (x + y * z) / y, x / y + z + ZERO * y);
-                    assert_eq((x + y * z) / y, x / y + z + ZERO * y);
+                    assert_eq_at((x + y * z) / y, x / y + z + ZERO * y, (x, y, z));
                 }
             }
         }
@@ -752,8 +1974,7 @@ mod test {
         for &x in any_numbers().iter() {
             for &y in any_numbers().iter() {
                 for &z in any_numbers().iter() {
-                    println!("{:?} == {:?}", (x + ZERO * y) * z, x * z + ZERO * y);
-                    assert_eq((x + ZERO * y) * z, x * z + ZERO * y);
+                    assert_eq_at((x + ZERO * y) * z, x * z + ZERO * y, (x, y, z));
                 }
             }
         }
@@ -764,8 +1985,7 @@ mod test {
     fn zero_times_y_inv() {
         for &x in any_numbers().iter() {
             for &y in any_numbers().iter() {
-                println!("{:?} == {:?}", (x + ZERO * y).inv(), x.inv() + ZERO * y);
-                assert_eq((x + ZERO * y).inv(), x.inv() + ZERO * y);
+                assert_eq_at((x + ZERO * y).inv(), x.inv() + ZERO * y, (x, y));
             }
         }
     }
@@ -774,8 +1994,7 @@ mod test {
     #[test]
     fn bottom_addition() {
         for &x in any_numbers().iter() {
-            println!("{:?} == {:?}", BOTTOM + x, BOTTOM);
-            assert_eq(BOTTOM + x, BOTTOM);
+            assert_eq_at(BOTTOM + x, BOTTOM, x);
         }
     }
 
@@ -784,8 +2003,7 @@ mod test {
     fn zero_times_x_plus_zero_times_y() {
         for &x in any_numbers().iter() {
             for &y in any_numbers().iter() {
-                println!("{:?} == {:?}", ZERO * x + ZERO * y, ZERO * x * y);
-                assert_eq(ZERO * x + ZERO * y, ZERO * x * y);
+                assert_eq_at(ZERO * x + ZERO * y, ZERO * x * y, (x, y));
             }
         }
     }
@@ -794,8 +2012,7 @@ mod test {
     #[test]
     fn x_div_x() {
         for &x in any_numbers().iter() {
-            println!("{:?} == {:?}", x / x, ONE + ZERO * x / x);
-            assert_eq(x / x, ONE + ZERO * x / x);
+            assert_eq_at(x / x, ONE + ZERO * x / x, x);
         }
     }
 
@@ -803,8 +2020,873 @@ mod test {
     #[test]
     fn x_minus_x() {
         for &x in any_numbers().iter() {
-            println!("{:?} == {:?}", x - x, ZERO * x * x);
-            assert_eq(x - x, ZERO * x * x);
+            assert_eq_at(x - x, ZERO * x * x, x);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn exp_formatting() {
+        use alloc::format;
+        assert_eq!(format!("{:e}", MyWheel::new(1234.0)), "1.234e3");
+        assert_eq!(format!("{:E}", MyWheel::new(1234.0)), "1.234E3");
+        assert_eq!(format!("{:e}", w32::new(0.5)), "5e-1");
+        assert_eq!(format!("{:e}", ZERO), "0");
+        assert_eq!(format!("{:e}", INFINITY), "Inf");
+        assert_eq!(format!("{:E}", BOTTOM), "Bottom");
+    }
+
+    #[test]
+    fn constructor_methods() {
+        let makers: [fn() -> MyWheel; 4] = [
+            <MyWheel as Wheel>::zero,
+            <MyWheel as Wheel>::one,
+            <MyWheel as Wheel>::infinity,
+            <MyWheel as Wheel>::bottom,
+        ];
+        assert_eq!(makers.map(|make| make()), [ZERO, ONE, INFINITY, BOTTOM]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn boxed_dyn_wheels() {
+        use alloc::{boxed::Box, vec, vec::Vec};
+        use crate::{qw32, DynWheel};
+        let values: Vec<Box<dyn DynWheel>> = vec![
+            Box::new(MyWheel::new(2.5)),
+            Box::new(w32::infinity()),
+            Box::new(qw32::new(1, 3)),
+            Box::new(qw32::zero()),
+            Box::new(MyWheel::bottom()),
+        ];
+        let classes: Vec<_> = values.iter().map(|x| x.class()).collect();
+        assert_eq!(classes, [
+            WheelClass::Normal, WheelClass::Infinity, WheelClass::Normal, WheelClass::Zero, WheelClass::Bottom,
+        ]);
+        assert_eq!(values.iter().filter(|x| x.invertible()).count(), 2);
+    }
+
+    #[test]
+    fn kahan_sum_is_accurate() {
+        let values = [MyWheel::new(1e100), ONE, MyWheel::new(-1e100)];
+        let naive = values.iter().fold(ZERO, |acc, x| acc + x);
+        assert_eq!(naive, ZERO);
+        assert_eq!(MyWheel::kahan_sum(&values), ONE);
+
+        let values = [MyWheel::new(0.1); 10];
+        assert_eq!(MyWheel::kahan_sum(&values), ONE);
+    }
+
+    #[test]
+    fn kahan_sum_specials() {
+        assert_eq!(MyWheel::kahan_sum(&[]), ZERO);
+        assert_eq!(MyWheel::kahan_sum(&[ONE, INFINITY, three()]), INFINITY);
+        assert_eq!(MyWheel::kahan_sum(&[INFINITY, ONE, INFINITY]), BOTTOM);
+        assert_eq!(MyWheel::kahan_sum(&[half(), BOTTOM, ZERO]), BOTTOM);
+    }
+
+    #[test]
+    fn kahan_sum_overflow() {
+        let max = MyWheel::new(f64::MAX);
+        for values in [[max, max, ONE], [max, ONE, max], [-max, -max, ONE], [max, max, -max]] {
+            assert_eq!(MyWheel::kahan_sum(&values), values.iter().sum::<MyWheel>(), "{:?}", values);
+        }
+        assert_eq!(MyWheel::kahan_sum(&[max, max]), INFINITY);
+        assert_eq!(MyWheel::kahan_sum(&[max, max, INFINITY]), BOTTOM);
+    }
+
+    #[test]
+    fn lerp() {
+        let a = negative_two();
+        let b = MyWheel::new(2.0);
+        assert_eq(a.lerp(&b, ZERO), a);
+        assert_eq(a.lerp(&b, ONE), b);
+        assert_eq(a.lerp(&b, half()), ZERO);
+        assert_eq(a.lerp(&b, MyWheel::new(0.75)), ONE);
+        assert_eq(Wheel::lerp(&a, &b, &half()), ZERO);
+    }
+
+    #[test]
+    fn lerp_infinite_endpoint() {
+        assert_eq(ONE.lerp(&INFINITY, half()), INFINITY);
+        assert_eq(ONE.lerp(&INFINITY, ONE), INFINITY);
+        assert_eq(ONE.lerp(&INFINITY, ZERO), BOTTOM);
+        assert_eq(INFINITY.lerp(&ONE, half()), BOTTOM);
+        assert_eq(ONE.lerp(&BOTTOM, half()), BOTTOM);
+        assert_eq(ONE.lerp(&three(), BOTTOM), BOTTOM);
+    }
+
+    #[test]
+    fn specials_classify() {
+        let expected = [WheelClass::Zero, WheelClass::Normal, WheelClass::Infinity, WheelClass::Bottom];
+        for (x, class) in MyWheel::specials().iter().zip(expected.iter()) {
+            assert_eq!(x.classify(), *class);
+        }
+        assert_eq!(negative_two().classify(), WheelClass::Normal);
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let values = [ONE, three(), half()];
+        let sum: MyWheel = values.iter().sum();
+        assert_eq(sum, MyWheel::new(4.5));
+        let product: MyWheel = values.iter().product();
+        assert_eq(product, MyWheel::new(1.5));
+        assert_eq(values.into_iter().sum(), MyWheel::new(4.5));
+        assert_eq(values.into_iter().product(), MyWheel::new(1.5));
+
+        let empty: [MyWheel; 0] = [];
+        assert_eq(empty.iter().sum(), ZERO);
+        assert_eq(empty.iter().product(), ONE);
+        assert_eq([ONE, INFINITY].iter().sum(), INFINITY);
+        assert_eq([ZERO, INFINITY].iter().product(), BOTTOM);
+        assert_eq!([w32::ONE, w32::new(2.0)].iter().sum::<w32>(), w32::new(3.0));
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq(three().pow(3), MyWheel::new(27.0));
+        assert_eq(half().pow(-2), MyWheel::new(4.0));
+        assert_eq(ZERO.pow(-1), INFINITY);
+        assert_eq(INFINITY.pow(2), INFINITY);
+        assert_eq(BOTTOM.pow(3), BOTTOM);
+        assert_eq(negative_two().pow(0), ONE);
+    }
+
+    #[test]
+    fn powi() {
+        for x in any_numbers() {
+            for exp in [-3, -2, -1, 0, 1, 2, 3, 7] {
+                assert_eq(x.powi(exp), x.pow(exp));
+            }
+        }
+        assert_eq!(ZERO.powi(0), ONE);
+        assert_eq!(ZERO.powi(-1), INFINITY);
+        assert_eq!(ZERO.powi(2), ZERO);
+        assert_eq!(INFINITY.powi(-2), ZERO);
+        assert_eq!(INFINITY.powi(3), INFINITY);
+        assert_eq!(BOTTOM.powi(-1), BOTTOM);
+        assert_eq!(MyWheel::new(1e200).powi(2), INFINITY);
+        assert_eq!(MyWheel::new(1e-200).powi(2), ZERO);
+        // 10^310 overflows, yet 10^-310 is a subnormal, not zero.
+        let tiny = MyWheel::new(10.0).powi(-310);
+        assert!((tiny.0 / 1e-310 - 1.0).abs() < 1e-12, "{:?}", tiny);
+        assert_eq!(w32::new(-2.0).powi(-3), w32::new(-0.125));
+        assert_eq!(w32::ZERO.powi(-1), w32::INFINITY);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn powi_matches_f64() {
+        for x in [1.5, -0.7, 3.0, 1e5, -1e-5, 0.1] {
+            for exp in [1, 2, 5, 16, 31] {
+                assert_eq!(MyWheel::new(x).powi(exp), MyWheel::new(x.powi(exp)), "{} {}", x, exp);
+                assert_eq(MyWheel::new(x).powi(-exp), MyWheel::new(x.powi(-exp)));
+            }
+        }
+    }
+
+    #[test]
+    fn atan() {
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6};
+        assert_eq!(INFINITY.atan(), MyWheel::new(FRAC_PI_2));
+        assert_eq!(ZERO.atan(), ZERO);
+        assert_eq!(BOTTOM.atan(), BOTTOM);
+        assert_eq!(ONE.atan(), MyWheel::new(FRAC_PI_4));
+        assert_eq!(negative_one().atan(), MyWheel::new(-FRAC_PI_4));
+        assert_eq(MyWheel::new(3.0f64.sqrt()).atan(), MyWheel::new(FRAC_PI_3));
+        assert_eq(MyWheel::new(1.0 / 3.0f64.sqrt()).atan(), MyWheel::new(FRAC_PI_6));
+        assert_eq(half().atan(), MyWheel::new(0.4636476090008061));
+        assert_eq(three().atan(), MyWheel::new(1.2490457723982544));
+        assert_eq(MyWheel::new(-1.3).atan(), MyWheel::new(-0.9151007005533605));
+        assert_eq(MyWheel::new(1e300).atan(), MyWheel::new(FRAC_PI_2));
+        assert_eq(MyWheel::new(1e-10).atan(), MyWheel::new(1e-10));
+    }
+
+    #[test]
+    fn tagged_bits_round_trip() {
+        let subnormal = MyWheel::new(f64::from_bits(1));
+        assert_eq!(subnormal.to_tagged_bits(), TaggedBits::Normal(1));
+        let restored = MyWheel::from_tagged_bits(subnormal.to_tagged_bits());
+        assert_eq!(restored.0.to_bits(), 1);
+
+        let small = w32::new(f32::MIN_POSITIVE / 3.0);
+        assert_eq!(w32::from_tagged_bits(small.to_tagged_bits()).0.to_bits(), small.0.to_bits());
+
+        for &x in any_numbers().iter() {
+            assert_eq!(MyWheel::from_tagged_bits(x.to_tagged_bits()), x);
+        }
+        assert_eq!(MyWheel::new(-0.0).to_tagged_bits(), TaggedBits::Zero);
+        assert_eq!(MyWheel::new(f64::NEG_INFINITY).to_tagged_bits(), TaggedBits::Infinity);
+        assert_eq!(BOTTOM.to_tagged_bits(), TaggedBits::Bottom);
+    }
+
+    #[test]
+    fn tolerance_wheel() {
+        type Coarse = ToleranceWheel64<4>;
+        type Fine = ToleranceWheel64<40>;
+        assert_eq!(Coarse::epsilon(), 0.0625);
+        assert_eq!(ToleranceWheel64::<-2>::epsilon(), 4.0);
+
+        let a = 1.0;
+        let b = 1.0 + 1e-3;
+        assert!(Coarse::new(a).roughly_eq(Coarse::new(b)));
+        assert!(!Fine::new(a).roughly_eq(Fine::new(b)));
+        assert_ne!(Coarse::new(a), Coarse::new(b));
+
+        let third = Fine::ONE / Fine::new(3.0);
+        assert!((third + third + third).roughly_eq(Fine::ONE));
+        assert!((Coarse::ONE / Coarse::ZERO).roughly_eq(Coarse::INFINITY));
+        assert!(!Coarse::BOTTOM.roughly_eq(Coarse::ZERO));
+    }
+
+    #[test]
+    fn tolerance_wheel_forwards_overrides() {
+        type T = ToleranceWheel64<20>;
+        let wrap = |v: [f64; 3]| v.map(T::new);
+        let raw = |v: [f64; 3]| v.map(MyWheel::new);
+        let a = [1e16, 1.0, -1e16];
+        let b = [1.0, 1.0, 1.0];
+        assert_eq!(<T as Wheel>::dot(&wrap(a), &wrap(b)).0, <MyWheel as Wheel>::dot(&raw(a), &raw(b)));
+        let x = [1.0 + f64::EPSILON, -1.0, 0.0];
+        let y = [1.0 - f64::EPSILON, 1.0, 0.0];
+        assert_eq!(T::sum_of_products(&wrap(x), &wrap(y)).0, MyWheel::sum_of_products(&raw(x), &raw(y)));
+        assert_eq!(<T as Wheel>::sub(&T::INFINITY, &T::INFINITY), T::BOTTOM);
+        assert_eq!(<T as Wheel>::div(&T::ONE, &T::ZERO), T::INFINITY);
+        assert_eq!(<T as Wheel>::approx_recip(&T::new(3.0), 4).0, MyWheel::new(3.0).approx_recip(4));
+        assert_eq!(<T as Wheel>::pow(&T::new(2.0), -2), T::new(0.25));
+        assert!(<T as Wheel>::is_one(&T::ONE));
+    }
+
+    #[test]
+    fn combinators() {
+        for &x in any_numbers().iter() {
+            assert_eq(x.neg_inv(), -(ONE / x));
+            assert_eq(x.one_minus(), ONE - x);
+            assert_eq(x.inv_one_minus(), ONE / (ONE - x));
+        }
+        assert_eq(ZERO.neg_inv(), INFINITY);
+        assert_eq(INFINITY.neg_inv(), ZERO);
+        assert_eq(BOTTOM.neg_inv(), BOTTOM);
+        assert_eq(INFINITY.one_minus(), INFINITY);
+        assert_eq(ONE.inv_one_minus(), INFINITY);
+        assert_eq(INFINITY.inv_one_minus(), ZERO);
+        assert_eq(BOTTOM.inv_one_minus(), BOTTOM);
+        assert_eq(half().inv_one_minus().inv_one_minus().inv_one_minus(), half());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_canonicalizes_specials() {
+        use std::collections::HashSet;
+        let zero = 0.0f64;
+        let bottoms = [
+            MyWheel::new(zero / zero),
+            MyWheel::new(f64::NAN),
+            MyWheel::new(f64::INFINITY - f64::INFINITY),
+            MyWheel::new(f64::from_bits(0x7ff8_0000_dead_beef)),
+            MyWheel::new(-f64::NAN),
+            BOTTOM,
+            ZERO / ZERO,
+            INFINITY + INFINITY,
+            ZERO * INFINITY,
+        ];
+        let set: HashSet<MyWheel> = bottoms.iter().copied().collect();
+        assert_eq!(set.len(), 1);
+
+        let set: HashSet<MyWheel> = [ZERO, MyWheel::new(-0.0), INFINITY, MyWheel::new(f64::NEG_INFINITY)].into_iter().collect();
+        assert_eq!(set.len(), 2);
+
+        let set: HashSet<w32> = [w32::BOTTOM, w32::new(f32::NAN), w32::new(f32::from_bits(0xffc0_0001))].into_iter().collect();
+        assert_eq!(set.len(), 1);
+
+        let set: HashSet<MyWheel> = any_numbers().into_iter().collect();
+        assert_eq!(set.len(), any_numbers().len());
+    }
+
+    #[test]
+    fn partial_ord() {
+        assert!(negative_two() < negative_one());
+        assert!(ZERO < half());
+        assert!(MyWheel::new(-0.0) >= ZERO);
+        assert!(three() > ONE);
+        assert_eq!(INFINITY.partial_cmp(&INFINITY), Some(Ordering::Equal));
+        assert_eq!(BOTTOM.partial_cmp(&BOTTOM), Some(Ordering::Equal));
+        assert_eq!(ONE.partial_cmp(&INFINITY), None);
+        assert_eq!(INFINITY.partial_cmp(&BOTTOM), None);
+        assert_eq!(ZERO.partial_cmp(&BOTTOM), None);
+        for &x in any_numbers().iter() {
+            for &y in any_numbers().iter() {
+                assert_eq!(x == y, x.partial_cmp(&y) == Some(Ordering::Equal));
+            }
+        }
+    }
+
+    #[test]
+    fn abs_sub() {
+        assert_eq(negative_two().abs(), MyWheel::new(2.0));
+        assert_eq(half().abs(), half());
+        assert_eq(ONE.abs_sub(&three()), MyWheel::new(2.0));
+        assert_eq(three().abs_sub(&ONE), MyWheel::new(2.0));
+        assert_eq(negative_quarter().abs_sub(&half()), MyWheel::new(0.75));
+        assert_eq(ONE.abs_sub(&ONE), ZERO);
+        assert_eq(ONE.abs_sub(&INFINITY), INFINITY);
+        assert_eq(INFINITY.abs_sub(&INFINITY), BOTTOM);
+        assert_eq(BOTTOM.abs_sub(&ONE), BOTTOM);
+        assert_eq(ZERO.abs_sub(&BOTTOM), BOTTOM);
+    }
+
+    #[test]
+    fn copysign() {
+        assert_eq(three().copysign(&negative_quarter()), MyWheel::new(-3.0));
+        assert_eq(negative_two().copysign(&half()), MyWheel::new(2.0));
+        assert_eq(negative_two().copysign(&negative_one()), negative_two());
+        assert_eq(half().copysign(&ZERO), half());
+        assert_eq(negative_two().copysign(&INFINITY), negative_two());
+        assert_eq(ZERO.copysign(&negative_one()), ZERO);
+        assert_eq(INFINITY.copysign(&negative_one()), INFINITY);
+        assert_eq(BOTTOM.copysign(&ONE), BOTTOM);
+        assert_eq(ONE.copysign(&BOTTOM), BOTTOM);
+        assert_eq!(w32::new(1.5).copysign(&w32::new(-0.1)), w32::new(-1.5));
+    }
+
+    #[test]
+    fn direct_sub_div() {
+        let values = [0.1, 0.3, 3.0, -7.7, 1e-300, 1.0 / 3.0, 123456.789];
+        for &a in values.iter() {
+            for &b in values.iter() {
+                let (x, y) = (MyWheel::new(a), MyWheel::new(b));
+                assert_eq!((x - y).0.to_bits(), (a - b).to_bits(), "{} - {}", a, b);
+                assert_eq!((x / y).0.to_bits(), (a / b).to_bits(), "{} / {}", a, b);
+                assert_eq!(Wheel::div(&x, &y).0.to_bits(), (a / b).to_bits());
+                assert_eq!(Wheel::sub(&x, &y).0.to_bits(), (a - b).to_bits());
+            }
+        }
+        assert_eq!((w32::new(0.1) / w32::new(0.3)).0.to_bits(), (0.1f32 / 0.3f32).to_bits());
+        assert_eq(INFINITY / INFINITY, BOTTOM);
+        assert_eq(INFINITY - INFINITY, BOTTOM);
+        assert_eq(ZERO / ZERO, BOTTOM);
+        assert_eq(ONE / ZERO, INFINITY);
+        assert_eq(ONE / INFINITY, ZERO);
+        assert_eq(three() - three(), ZERO);
+        assert_eq(MyWheel::new(1e300) / MyWheel::new(1e-300), INFINITY);
+    }
+
+    /// `a / b` over all combinations of categories, with `n` a normal value.
+    #[test]
+    fn div_category_matrix() {
+        let n = three();
+        let values = [ZERO, n, INFINITY, BOTTOM];
+        let expected = [
+            [BOTTOM, ZERO, ZERO, BOTTOM],
+            [INFINITY, ONE, ZERO, BOTTOM],
+            [INFINITY, INFINITY, BOTTOM, BOTTOM],
+            [BOTTOM, BOTTOM, BOTTOM, BOTTOM],
+        ];
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                assert_eq!(a / b, expected[i][j], "{:?} / {:?}", a, b);
+                assert_eq!(Wheel::div(a, b), a.mul(b.inv()), "{:?} / {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn sign() {
+        let expected = [
+            Sign::Zero, Sign::Positive, Sign::Infinite, Sign::Bottom,
+            Sign::Negative, Sign::Positive, Sign::Negative, Sign::Positive, Sign::Negative,
+        ];
+        assert_eq!(any_numbers().map(|x| x.sign()), expected);
+        assert_eq!(MyWheel::new(-0.0).sign(), Sign::Zero);
+        assert_eq!(MyWheel::new(f64::NEG_INFINITY).sign(), Sign::Infinite);
+        assert_eq!(w32::new(-1e-40).sign(), Sign::Negative);
+        assert_eq!(w32::new(f32::NAN).sign(), Sign::Bottom);
+    }
+
+    #[test]
+    fn signum_wheel() {
+        let expected = [ZERO, ONE, INFINITY, BOTTOM, MyWheel::NEGATIVE_ONE, ONE, MyWheel::NEGATIVE_ONE, ONE, MyWheel::NEGATIVE_ONE];
+        assert_eq!(any_numbers().map(|x| x.signum_wheel()), expected);
+        for x in any_numbers() {
+            let from_enum = match x.sign() {
+                Sign::Positive => Some(ONE),
+                Sign::Negative => Some(MyWheel::NEGATIVE_ONE),
+                Sign::Zero => Some(ZERO),
+                Sign::Infinite | Sign::Bottom => None,
+            };
+            match from_enum {
+                Some(expected) => assert_eq!(x.signum_wheel(), expected),
+                None => assert_eq!(x.signum_wheel(), x),
+            }
+        }
+        // Composes: x = |x| · signum(x) for finite x.
+        let x = MyWheel::new(-2.5);
+        assert_eq!(x.abs().mul(x.signum_wheel()), x);
+        assert_eq!(INFINITY.signum_wheel().mul(ZERO), BOTTOM);
+    }
+
+    #[test]
+    fn is_invertible() {
+        for x in any_numbers() {
+            assert_eq!(x.is_invertible(), x.classify() == WheelClass::Normal, "x = {:?}", x);
+            if x.is_invertible() {
+                assert_eq_at(x * x.inv(), ONE, x);
+            } else {
+                assert_ne!(x * x.inv(), ONE, "x = {:?}", x);
+            }
+        }
+        assert!(MyWheel::new(49.0).is_invertible());
+        assert!(!MyWheel::new(-0.0).is_invertible());
+        assert!(!MyWheel::new(f64::NAN).is_invertible());
+    }
+
+    #[test]
+    fn recip_sqrt() {
+        assert_eq!(MyWheel::new(4.0).recip_sqrt(), MyWheel::new(0.5));
+        assert_eq!(MyWheel::new(0.0625).recip_sqrt(), MyWheel::new(4.0));
+        assert_eq(MyWheel::new(2.0).recip_sqrt(), MyWheel::new(core::f64::consts::FRAC_1_SQRT_2));
+        assert_eq!(MyWheel::new(f64::from_bits(1)).recip_sqrt(), MyWheel::new(2f64.powi(537)));
+        assert_eq!(ZERO.recip_sqrt(), INFINITY);
+        assert_eq!(INFINITY.recip_sqrt(), ZERO);
+        assert_eq!(MyWheel::new(-4.0).recip_sqrt(), BOTTOM);
+        assert_eq!(BOTTOM.recip_sqrt(), BOTTOM);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn recip_sqrt_matches_std() {
+        for x in [1.0, 2.0, 3.0, 10.0, 1e-300, 5e-324, 1.7976931348623157e308, 0.1, 123456.789] {
+            assert_eq!(MyWheel::new(x).recip_sqrt(), MyWheel::new(1.0 / x.sqrt()), "x = {:?}", x);
+        }
+    }
+
+    #[test]
+    fn partial_min_max() {
+        let (a, b) = (MyWheel::new(-2.0), MyWheel::new(0.5));
+        assert_eq!(a.partial_min(&b), Some(a));
+        assert_eq!(b.partial_min(&a), Some(a));
+        assert_eq!(a.partial_max(&b), Some(b));
+        assert_eq!(ZERO.partial_max(&a), Some(ZERO));
+        assert_eq!(INFINITY.partial_min(&INFINITY), Some(INFINITY));
+        assert_eq!(INFINITY.partial_max(&a), None);
+        for x in any_numbers() {
+            assert_eq!(x.partial_min(&BOTTOM), None, "x = {:?}", x);
+            assert_eq!(BOTTOM.partial_max(&x), None, "x = {:?}", x);
+        }
+    }
+
+    #[test]
+    fn finite_fast_path() {
+        let values = [
+            ZERO, MyWheel::new(-0.0), ONE, MyWheel::new(-1.5), MyWheel::new(f64::MAX),
+            MyWheel::new(f64::MIN_POSITIVE), MyWheel::new(5e-324), INFINITY,
+            MyWheel::new(f64::NEG_INFINITY), BOTTOM,
+        ];
+        for x in values {
+            for y in values {
+                assert_eq!(x + y, x.add_by_category(y), "x = {:?}, y = {:?}", x, y);
+                assert_eq!(x * y, x.mul_by_category(y), "x = {:?}, y = {:?}", x, y);
+            }
+        }
+        let x = w32::new(f32::MAX);
+        assert_eq!(x + x, x.add_by_category(x));
+        assert_eq!(x * w32::ZERO, x.mul_by_category(w32::ZERO));
+        assert_eq!(w32::INFINITY * w32::ZERO, w32::BOTTOM);
+    }
+
+    #[test]
+    fn strict_bottom_comparison() {
+        assert_eq!(BOTTOM, BOTTOM);
+        assert_ne!(StrictWheel64::BOTTOM, StrictWheel64::BOTTOM);
+        assert_ne!(StrictWheel64::ZERO / StrictWheel64::ZERO, StrictWheel64::BOTTOM);
+        assert!((StrictWheel64::ZERO / StrictWheel64::ZERO).is_bottom());
+        assert_eq!(StrictWheel64::BOTTOM.partial_cmp(&StrictWheel64::BOTTOM), None);
+        assert_eq!(BOTTOM.partial_cmp(&BOTTOM), Some(Ordering::Equal));
+
+        assert_eq!(StrictWheel64::INFINITY, StrictWheel64::ONE / StrictWheel64::ZERO);
+        assert_eq!(StrictWheel64::new(-0.0), StrictWheel64::ZERO);
+        assert_eq!(StrictWheel64::new(1.5) + StrictWheel64::ONE, StrictWheel64::new(2.5));
+        assert_eq!(StrictWheel64::new(4.0).inv(), StrictWheel64::new(0.25));
+        assert!(StrictWheel64::ONE < StrictWheel64::new(2.0));
+    }
+
+    #[test]
+    fn approx_recip() {
+        for x in [1.0, 3.0, -7.5, 0.001, 123456.0, -1e-300, 1e300] {
+            let y = MyWheel::new(x).approx_recip(0);
+            assert!((y.0 * x - 1.0).abs() < 0.13, "x = {:?}", x);
+            let y = MyWheel::new(x).approx_recip(6);
+            assert!((y.0 * x - 1.0).abs() < 1e-15, "x = {:?}", x);
+        }
+        for x in [0.5f32, -3.0, 1e30] {
+            let y = w32::new(x).approx_recip(4);
+            assert!((y.0 * x - 1.0).abs() < 1e-6, "x = {:?}", x);
+        }
+        assert_eq!(ZERO.approx_recip(3), INFINITY);
+        assert_eq!(INFINITY.approx_recip(3), ZERO);
+        assert_eq!(BOTTOM.approx_recip(3), BOTTOM);
+        assert_eq!(MyWheel::new(5e-324).approx_recip(3), MyWheel::new(5e-324).inv());
+        assert_eq(Wheel::approx_recip(&MyWheel::new(4.0), 6), MyWheel::new(0.25));
+        assert_eq!(Wheel::approx_recip(&crate::qw32::new(3, 4), 1), crate::qw32::new(4, 3));
+    }
+
+    #[test]
+    fn clamp_to_finite() {
+        assert_eq!(INFINITY.clamp_to_finite().inner(), f64::MAX);
+        assert_eq!(MyWheel::new(f64::NEG_INFINITY).clamp_to_finite().inner(), f64::MAX);
+        assert_eq!(BOTTOM.clamp_to_finite(), ZERO);
+        assert_eq!(BOTTOM.try_clamp_to_finite(), None);
+        assert_eq!(INFINITY.try_clamp_to_finite(), Some(MyWheel::new(f64::MAX)));
+        for x in any_numbers() {
+            if x != INFINITY && x != BOTTOM {
+                assert_eq!(x.clamp_to_finite().inner(), x.inner(), "x = {:?}", x);
+            }
+        }
+        assert_eq!(w32::INFINITY.clamp_to_finite().inner(), f32::MAX);
+    }
+
+    #[test]
+    fn next_up_down() {
+        let tiny = MyWheel::new(5e-324);
+        assert_eq!(ZERO.next_up(), tiny);
+        assert_eq!(MyWheel::new(-0.0).next_up(), tiny);
+        assert_eq!(ZERO.next_down(), -tiny);
+        assert_eq!(tiny.next_down(), ZERO);
+        assert_eq!((-tiny).next_up(), ZERO);
+        assert_eq!(ONE.next_up().inner(), 1.0 + f64::EPSILON);
+        assert_eq!(ONE.next_up().next_down(), ONE);
+
+        let max = MyWheel::new(f64::MAX);
+        assert_eq!(max.next_up(), INFINITY);
+        assert_eq!(INFINITY.next_down(), max);
+        assert_eq!(MyWheel::new(f64::NEG_INFINITY).next_down(), max);
+        assert_eq!(INFINITY.next_up(), MyWheel::new(f64::MIN));
+        assert_eq!(MyWheel::new(f64::MIN).next_down(), INFINITY);
+        assert_eq!(BOTTOM.next_up(), BOTTOM);
+        assert_eq!(BOTTOM.next_down(), BOTTOM);
+        assert_eq!(w32::new(f32::MAX).next_up(), w32::INFINITY);
+        assert_eq!(w32::INFINITY.next_down(), w32::new(f32::MAX));
+    }
+
+    #[test]
+    fn from_f64() {
+        fn half<W: Wheel>() -> W {
+            W::from_f64(0.5)
+        }
+        assert_eq!(half::<MyWheel>(), MyWheel::new(0.5));
+        assert_eq!(half::<w32>(), w32::new(0.5));
+        assert_eq!(half::<ToleranceWheel64<20>>(), ToleranceWheel64::new(0.5));
+        assert_eq!(<MyWheel as Wheel>::from_f64(f64::NAN), BOTTOM);
+        assert_eq!(<MyWheel as Wheel>::from_f64(f64::NEG_INFINITY), INFINITY);
+        assert_eq!(<w32 as Wheel>::from_f64(1e300), w32::INFINITY);
+    }
+
+    #[test]
+    fn ordered_f64() {
+        assert_eq!(MyWheel::new(-0.0).to_ordered_f64().to_bits(), 0.0f64.to_bits());
+        assert_eq!(MyWheel::new(f64::NEG_INFINITY).to_ordered_f64(), f64::INFINITY);
+        assert!(BOTTOM.to_ordered_f64().is_nan());
+        assert!(BOTTOM.to_ordered_f64().is_sign_positive());
+        assert_eq!(MyWheel::new(-2.5).to_ordered_f64(), -2.5);
+        for x in [ZERO, INFINITY, BOTTOM, MyWheel::new(-2.5), MyWheel::new(f64::MIN_POSITIVE)] {
+            assert_eq!(MyWheel::from_ordered_f64(x.to_ordered_f64()), x);
+        }
+    }
+
+    #[test]
+    fn ordered_f64_sorts_like_total_cmp() {
+        let values = [
+            BOTTOM, MyWheel::new(3.0), INFINITY, MyWheel::new(-0.0), MyWheel::new(-1e300),
+            MyWheel::new(f64::NEG_INFINITY), MyWheel::new(f64::from_bits(1)), MyWheel::new(-0.5),
+        ];
+        for a in values {
+            for b in values {
+                assert_eq!(a.to_ordered_f64().total_cmp(&b.to_ordered_f64()), a.total_cmp(&b), "{:?} {:?}", a, b);
+            }
+        }
+        let mut by_wheel = values;
+        by_wheel.sort_by(MyWheel::total_cmp);
+        let mut by_float = values.map(|x| x.to_ordered_f64());
+        by_float.sort_by(f64::total_cmp);
+        assert_eq!(by_wheel.map(|x| x.to_ordered_f64().to_bits()), by_float.map(f64::to_bits));
+    }
+
+    #[test]
+    fn compensated_dot() {
+        let a = [MyWheel::new(1e16), ONE, MyWheel::new(-1e16), MyWheel::new(0.5)];
+        let b = [ONE, ONE, ONE, MyWheel::new(2.0)];
+        let naive = a.iter().zip(&b).fold(ZERO, |acc, (x, y)| acc + x * y);
+        assert_eq!(naive, ONE);
+        assert_eq!(MyWheel::dot(&a, &b), MyWheel::new(2.0));
+        assert_eq!(<MyWheel as Wheel>::dot(&a, &b), MyWheel::new(2.0));
+        assert_eq!(MyWheel::dot(&a, &b[..2]), MyWheel::new(1e16 + 1.0));
+        assert_eq!(MyWheel::dot(&[], &[]), ZERO);
+    }
+
+    #[test]
+    fn compensated_dot_specials() {
+        assert_eq!(MyWheel::dot(&[ONE, INFINITY], &[three(), half()]), INFINITY);
+        assert_eq!(MyWheel::dot(&[ZERO, ONE], &[INFINITY, ONE]), BOTTOM);
+        assert_eq!(MyWheel::dot(&[INFINITY, INFINITY], &[ONE, ONE]), BOTTOM);
+        assert_eq!(MyWheel::dot(&[BOTTOM, ONE], &[ONE, ONE]), BOTTOM);
+    }
+
+    #[test]
+    fn compensated_dot_overflow() {
+        let plain = |a: &[MyWheel], b: &[MyWheel]| a.iter().zip(b).fold(ZERO, |acc, (x, y)| acc + x * y);
+        // Each product is finite; only their sum overflows.
+        let a = [MyWheel::new(1e300), MyWheel::new(1e300)];
+        let b = [MyWheel::new(1e8), MyWheel::new(1e8)];
+        assert_eq!(MyWheel::dot(&a, &b), INFINITY);
+        assert_eq!(<MyWheel as Wheel>::dot(&a, &b), INFINITY);
+        assert_eq!(MyWheel::sum_of_products(&a, &b), INFINITY);
+        assert_eq!(plain(&a, &b), INFINITY);
+        // Products that overflow on their own are each `INFINITY`, and two of those add to `BOTTOM`.
+        let b = [MyWheel::new(1e10), MyWheel::new(1e10)];
+        assert_eq!(MyWheel::dot(&a, &b), plain(&a, &b));
+        assert_eq!(MyWheel::sum_of_products(&a, &b), plain(&a, &b));
+        let b = [MyWheel::new(1e10), ONE];
+        assert_eq!(MyWheel::dot(&a, &b), INFINITY);
+        assert_eq!(MyWheel::sum_of_products(&a, &b), INFINITY);
+    }
+
+    #[test]
+    fn is_close_to_integer() {
+        assert!(MyWheel::new(2.0000001).is_close_to_integer(1e-3));
+        assert!(MyWheel::new(-2.9999).is_close_to_integer(1e-3));
+        assert!(!MyWheel::new(2.5).is_close_to_integer(1e-3));
+        assert!(MyWheel::new(1e-9).is_close_to_integer(1e-3));
+        assert!(MyWheel::new(3.0).is_close_to_integer(0.0));
+        assert!(MyWheel::new(1e300).is_close_to_integer(0.0));
+        assert!(!ZERO.is_close_to_integer(1.0));
+        assert!(!MyWheel::new(-0.0).is_close_to_integer(1.0));
+        assert!(!w32::ZERO.is_close_to_integer(1.0));
+        assert!(!INFINITY.is_close_to_integer(1.0));
+        assert!(!BOTTOM.is_close_to_integer(1.0));
+        assert!(w32::new(7.00001).is_close_to_integer(1e-3));
+        assert!(<ToleranceWheel64<20> as Wheel>::is_close_to_integer(&ToleranceWheel64::new(4.0), 0.0));
+        assert!(!<ToleranceWheel64<20> as Wheel>::is_close_to_integer(&ToleranceWheel64::ZERO, 1.0));
+    }
+
+    #[test]
+    fn le_bytes_round_trip() {
+        for x in [ZERO, INFINITY, BOTTOM, ONE, MyWheel::new(-2.5), MyWheel::new(f64::from_bits(1))] {
+            let bytes = x.to_le_bytes();
+            assert_eq!(MyWheel::try_from_le_bytes(bytes), Ok(x));
+            assert_eq!(MyWheel::from_le_bytes(bytes).0.to_bits(), x.to_ordered_f64().to_bits());
+        }
+        assert_eq!(MyWheel::new(-0.0).to_le_bytes(), [0; 9]);
+        assert_eq!(MyWheel::new(f64::NEG_INFINITY).to_le_bytes(), [2, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(MyWheel::new(-f64::NAN).to_le_bytes(), [3, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(ONE.to_le_bytes(), [1, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f]);
+        for x in [ZERO, ONE, INFINITY, BOTTOM] {
+            assert_eq!(x.to_le_bytes()[0], x.decompose().0);
+        }
+    }
+
+    #[test]
+    fn le_bytes_corrupt() {
+        let mut bytes = ONE.to_le_bytes();
+        bytes[0] = 4;
+        assert_eq!(MyWheel::try_from_le_bytes(bytes), Err(WheelError::InvalidFormat));
+        assert_eq!(MyWheel::from_le_bytes(bytes), BOTTOM);
+        // A payload under a special tag.
+        bytes[0] = 2;
+        assert_eq!(MyWheel::try_from_le_bytes(bytes), Err(WheelError::InvalidFormat));
+        // The normal tag with the bits of zero, infinity or NaN.
+        for x in [0.0, f64::INFINITY, f64::NAN] {
+            let mut bytes = [1; 9];
+            bytes[1..].copy_from_slice(&x.to_bits().to_le_bytes());
+            assert_eq!(MyWheel::try_from_le_bytes(bytes), Err(WheelError::InvalidFormat));
+        }
+    }
+
+    #[test]
+    fn frexp_ldexp() {
+        assert_eq!(MyWheel::new(8.0).frexp(), (MyWheel::new(0.5), 4));
+        assert_eq!(MyWheel::new(-3.0).frexp(), (MyWheel::new(-0.75), 2));
+        assert_eq!(MyWheel::new(f64::from_bits(1)).frexp(), (MyWheel::new(0.5), -1073));
+        assert_eq!(ZERO.frexp(), (ZERO, 0));
+        assert_eq!(INFINITY.frexp(), (INFINITY, 0));
+        assert_eq!(BOTTOM.frexp(), (BOTTOM, 0));
+        for x in [1.0, -3.0, 0.1, 1e300, -1e-300, f64::MAX, f64::MIN_POSITIVE, f64::from_bits(12345)] {
+            let (mantissa, exponent) = MyWheel::new(x).frexp();
+            assert!((0.5..1.0).contains(&mantissa.0.abs()), "x = {:?}", x);
+            assert_eq!(mantissa.ldexp(exponent).0.to_bits(), x.to_bits(), "x = {:?}", x);
+        }
+        for x in [1.0f32, -3.0, 0.1, 1e38, f32::from_bits(7)] {
+            let (mantissa, exponent) = w32::new(x).frexp();
+            assert_eq!(mantissa.ldexp(exponent), w32::new(x), "x = {:?}", x);
+        }
+    }
+
+    #[test]
+    fn ldexp_range() {
+        assert_eq!(ONE.ldexp(-1074), MyWheel::new(f64::from_bits(1)));
+        assert_eq!(ONE.ldexp(-1075), ZERO);
+        assert_eq!(MyWheel::new(1.5).ldexp(-1074), MyWheel::new(f64::from_bits(2)));
+        assert_eq!(ONE.ldexp(1023), MyWheel::new(f64::from_bits(0x7fe << 52)));
+        assert_eq!(ONE.ldexp(1024), INFINITY);
+        assert_eq!(MyWheel::new(f64::from_bits(1)).ldexp(2000), MyWheel::new(2f64.powi(926)));
+        assert_eq!(MyWheel::new(f64::MAX).ldexp(-2000), MyWheel::new(f64::MAX / 2f64.powi(1000) / 2f64.powi(1000)));
+        assert_eq!(ONE.ldexp(i32::MAX), INFINITY);
+        assert_eq!(ONE.ldexp(i32::MIN), ZERO);
+        assert_eq!(INFINITY.ldexp(-5), INFINITY);
+        assert_eq!(BOTTOM.ldexp(5), BOTTOM);
+        assert_eq!(w32::ONE.ldexp(-149), w32::new(f32::from_bits(1)));
+        assert_eq!(w32::ONE.ldexp(128), w32::INFINITY);
+    }
+
+    #[test]
+    fn sum_of_products() {
+        // (1e8 + 1)(1e8 - 1) = 1e16 - 1 rounds to 1e16, so both sums lose the -1.
+        let a = [MyWheel::new(1e8 + 1.0), MyWheel::new(-1e8)];
+        let b = [MyWheel::new(1e8 - 1.0), MyWheel::new(1e8)];
+        assert_eq!(MyWheel::dot(&a, &b), ZERO);
+        assert_eq!(MyWheel::sum_of_products(&a, &b), MyWheel::NEGATIVE_ONE);
+        assert_eq!(<MyWheel as Wheel>::sum_of_products(&a, &b), MyWheel::NEGATIVE_ONE);
+        let a = [MyWheel::new(0.1), MyWheel::new(0.1), MyWheel::new(-0.1)];
+        let b = [MyWheel::new(3.0), MyWheel::new(7.0), MyWheel::new(10.0)];
+        assert_eq!(MyWheel::sum_of_products(&a, &b), ZERO);
+        assert_eq!(MyWheel::sum_of_products(&[], &[]), ZERO);
+    }
+
+    #[test]
+    fn sum_of_products_specials() {
+        assert_eq!(MyWheel::sum_of_products(&[ONE, INFINITY], &[three(), half()]), INFINITY);
+        assert_eq!(MyWheel::sum_of_products(&[ZERO, ONE], &[INFINITY, ONE]), BOTTOM);
+        assert_eq!(MyWheel::sum_of_products(&[MyWheel::new(1e200), ONE], &[MyWheel::new(1e200), ONE]), INFINITY);
+        assert_eq!(MyWheel::sum_of_products(&[BOTTOM], &[ONE]), BOTTOM);
+    }
+
+    #[test]
+    fn subnormal() {
+        let tiny = MyWheel::new(f64::MIN_POSITIVE / 4.0);
+        assert!(tiny.is_subnormal());
+        assert!(!tiny.is_normal_strict());
+        assert_eq!(tiny.classify(), WheelClass::Normal);
+        assert!(MyWheel::new(-f64::from_bits(1)).is_subnormal());
+        assert!(!MyWheel::new(f64::MIN_POSITIVE).is_subnormal());
+        assert!(MyWheel::new(f64::MIN_POSITIVE).is_normal_strict());
+        for x in [ZERO, INFINITY, BOTTOM] {
+            assert!(!x.is_subnormal());
+            assert!(!x.is_normal_strict());
+        }
+    }
+
+    #[test]
+    fn relative_error_to() {
+        let error = MyWheel::new(1.01).relative_error_to(&ONE);
+        assert!((error.0 - 0.01).abs() < 1e-12);
+        assert_eq!(MyWheel::new(2.5).relative_error_to(&MyWheel::new(5.0)), MyWheel::new(-0.5));
+        assert_eq!(three().relative_error_to(&three()), ZERO);
+        assert_eq!(three().relative_error_to(&ZERO), INFINITY);
+        assert_eq!(ZERO.relative_error_to(&ZERO), BOTTOM);
+        assert_eq!(three().relative_error_to(&INFINITY), BOTTOM);
+    }
+
+    #[test]
+    fn recip_checked() {
+        assert_eq!(MyWheel::new(2.0).recip_checked(), (half(), true));
+        assert_eq!(MyWheel::new(-0.125).recip_checked(), (MyWheel::new(-8.0), true));
+        assert_eq!(three().recip_checked(), (MyWheel::new(1.0 / 3.0), false));
+        assert_eq!(MyWheel::new(0.1).recip_checked(), (MyWheel::new(10.0), false));
+        // 2^-1023 is subnormal, 2^-1074 has no finite reciprocal.
+        let (inverse, exact) = MyWheel::new(f64::MIN_POSITIVE / 2.0).recip_checked();
+        assert!(exact);
+        assert_eq!(inverse, MyWheel::new(f64::from_bits(0x7fe << 52)));
+        assert_eq!(MyWheel::new(f64::from_bits(1)).recip_checked(), (INFINITY, false));
+        assert_eq!(ZERO.recip_checked(), (INFINITY, true));
+        assert_eq!(INFINITY.recip_checked(), (ZERO, true));
+        assert_eq!(BOTTOM.recip_checked(), (BOTTOM, true));
+    }
+
+    #[test]
+    fn checked_sub_div() {
+        assert_eq!(INFINITY.checked_sub(&INFINITY), Err(WheelError::Undefined));
+        assert_eq!(three().checked_sub(&half()), Ok(MyWheel::new(2.5)));
+        assert_eq!(INFINITY.checked_sub(&three()), Ok(INFINITY));
+        assert_eq!(three().checked_div(&ZERO), Ok(INFINITY));
+        assert_eq!(ZERO.checked_div(&ZERO), Err(WheelError::Undefined));
+        assert_eq!(BOTTOM.checked_div(&ONE), Err(WheelError::Undefined));
+    }
+
+    #[test]
+    fn fma_slice() {
+        let xs = [MyWheel::new(1.5), MyWheel::new(-2.0), MyWheel::new(0.25)];
+        let ws = [MyWheel::new(2.0), MyWheel::new(3.0), MyWheel::new(8.0)];
+        let manual = xs.iter().zip(&ws).fold(MyWheel::new(10.0), |acc, (x, w)| acc + x * w);
+        assert_eq!(MyWheel::fma_slice(MyWheel::new(10.0), &xs, &ws), manual);
+        assert_eq!(MyWheel::fma_slice(ONE, &[], &[]), ONE);
+        assert_eq!(MyWheel::fma_slice(ONE, &xs, &ws[..1]), MyWheel::new(4.0));
+        // 1e308 · 10 overflows to INFINITY even though the sum would come back down.
+        assert_eq!(MyWheel::fma_slice(MyWheel::new(-1e308), &[MyWheel::new(1e308)], &[MyWheel::new(10.0)]), INFINITY);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fma_slice_is_fused() {
+        // (1 + 2^-30)² = 1 + 2^-29 + 2^-60; only the fused form keeps the 2^-60.
+        let x = MyWheel::new(1.0 + 2f64.powi(-30));
+        let acc = MyWheel::new(-(1.0 + 2f64.powi(-29)));
+        assert_eq!(MyWheel::fma_slice(acc, &[x], &[x]), MyWheel::new(2f64.powi(-60)));
+        assert_eq!(acc + x * x, ZERO);
+    }
+
+    #[test]
+    fn fma_slice_specials() {
+        assert_eq!(MyWheel::fma_slice(ONE, &[INFINITY, three()], &[half(), ONE]), INFINITY);
+        assert_eq!(MyWheel::fma_slice(ONE, &[ZERO, three()], &[INFINITY, ONE]), BOTTOM);
+        assert_eq!(MyWheel::fma_slice(INFINITY, &[INFINITY], &[ONE]), BOTTOM);
+        assert_eq!(MyWheel::fma_slice(BOTTOM, &[ONE], &[ONE]), BOTTOM);
+        assert_eq!(MyWheel::fma_slice(ONE, &[BOTTOM, ONE], &[ONE, ONE]), BOTTOM);
+    }
+
+    #[test]
+    fn scale_by_pow2() {
+        fn scale<W: Wheel>(x: &W, k: i32) -> W {
+            x.scale_by_pow2(k)
+        }
+        for x in [three(), MyWheel::new(-0.1), MyWheel::new(1e-310)] {
+            assert_eq!(scale(&x, 3), x * MyWheel::new(8.0));
+            assert_eq!(scale(&x, -2), x * MyWheel::new(0.25));
+        }
+        assert_eq!(scale(&half(), 1100), INFINITY);
+        assert_eq!(scale(&MyWheel::new(f64::MAX), -2100), ZERO);
+        assert_eq!(scale(&w32::new(1.5), 3), w32::new(12.0));
+        assert_eq!(scale(&ToleranceWheel64::<20>::new(1.5), -1), ToleranceWheel64::new(0.75));
+        for x in [ZERO, INFINITY, BOTTOM] {
+            assert_eq!(scale(&x, 5), x);
+        }
+    }
+
+    #[test]
+    fn decompose_compose() {
+        assert_eq!(ZERO.decompose(), (0, 0.0));
+        assert_eq!(MyWheel::new(-2.5).decompose(), (1, -2.5));
+        assert_eq!(INFINITY.decompose(), (2, 0.0));
+        assert_eq!(BOTTOM.decompose(), (3, 0.0));
+        assert_eq!(MyWheel::new(-0.0).decompose().1.to_bits(), 0);
+        for x in [ZERO, ONE, MyWheel::new(-2.5), MyWheel::new(f64::from_bits(1)), INFINITY, BOTTOM] {
+            let (code, value) = x.decompose();
+            assert_eq!(MyWheel::compose(code, value), x);
+        }
+        assert_eq!(MyWheel::compose(2, 7.0), INFINITY);
+        assert_eq!(MyWheel::compose(4, 7.0), BOTTOM);
+    }
+
+    #[test]
+    fn is_one() {
+        for x in any_numbers() {
+            assert_eq!(x.is_one(), x == ONE, "x = {:?}", x);
+        }
+        for x in <MyWheel as crate::laws::WheelSamples>::samples() {
+            assert_eq!(Wheel::is_one(x), *x == Wheel64::ONE, "x = {:?}", x);
         }
+        assert!(MyWheel::new(1.0).is_one());
+        assert!(!MyWheel::new(1.0).next_up().is_one());
+        assert!(w32::ONE.is_one());
+        assert!(!w32::INFINITY.is_one());
     }
 }
@@ -1,9 +1,15 @@
 //! Wheel implementation for fractions.
 
-use crate::Wheel;
+use crate::{Sign, Wheel, WheelClass, WheelError};
 
 use core::ops::{Add, Sub, Mul, Div, Neg, Rem};
-use core::fmt::Debug;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter::{Sum, Product};
+use core::cmp::Ordering;
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 
 pub trait Ring: Add<Output=Self> + Mul<Output=Self> + Neg<Output=Self> + Copy + Clone + PartialEq + Eq + PartialOrd + Debug {
     const ZERO: Self;
@@ -23,6 +29,44 @@ pub trait Ring: Add<Output=Self> + Mul<Output=Self> + Neg<Output=Self> + Copy +
         }
     }
 
+    /// Compares two pairs that have already been through `normalize_pair`.
+    /// Rings whose normalized form is canonical can compare components directly.
+    fn compare_normalized_pairs(a: (Self, Self), b: (Self, Self)) -> bool {
+        Self::compare_pairs(a, b)
+    }
+
+    /// Orders two finite normalized pairs, whose denominators are positive.
+    fn cmp_pairs(a: (Self, Self), b: (Self, Self)) -> Option<Ordering> {
+        (a.0 * b.1).partial_cmp(&(b.0 * a.1))
+    }
+
+    /// Numerator and denominator of `a + b`, before normalization.
+    fn add_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        (a.0 * b.1 + a.1 * b.0, a.1 * b.1)
+    }
+
+    /// Numerator and denominator of `a - b`, before normalization.
+    fn sub_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        Self::add_pairs(a, Self::neg_pair(b))
+    }
+
+    /// Numerator and denominator of `a * b`, before normalization.
+    fn mul_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        (a.0 * b.0, a.1 * b.1)
+    }
+
+    /// Numerator and denominator of `-a`, before normalization.
+    fn neg_pair(a: (Self, Self)) -> (Self, Self) {
+        (-a.0, a.1)
+    }
+
+    /// Numerator and denominator for an `f64`. Rings without a conversion
+    /// give `BOTTOM`.
+    fn from_f64_pair(value: f64) -> (Self, Self) {
+        let _ = value;
+        (Self::ZERO, Self::ZERO)
+    }
+
     fn normalize_pair(pair: (Self, Self)) -> (Self, Self) {
         let first_is_zero = pair.0 == Self::ZERO;
         let second_is_zero = pair.1 == Self::ZERO;
@@ -37,7 +81,8 @@ pub trait Ring: Add<Output=Self> + Mul<Output=Self> + Neg<Output=Self> + Copy +
     }
 }
 
-trait Gcd: Ring + Rem<Output=Self> + Ord {
+/// Integer operations beyond those of a ring.
+pub trait Gcd: Ring + Sub<Output=Self> + Div<Output=Self> + Rem<Output=Self> + Ord {
     fn abs(&self) -> Self {
         if *self < Self::ZERO {
             -*self
@@ -47,19 +92,251 @@ trait Gcd: Ring + Rem<Output=Self> + Ord {
     }
 
     fn gcd(a: Self, b: Self) -> Self {
-        let mut a = a.abs();
-        let mut b = b.abs();
+        -Self::neg_gcd(a, b)
+    }
+
+    /// The negated gcd (`-1` if both are zero).
+    /// Working with non-positive values keeps this defined for `MIN`.
+    fn neg_gcd(a: Self, b: Self) -> Self {
+        let mut a = if a > Self::ZERO { -a } else { a };
+        let mut b = if b > Self::ZERO { -b } else { b };
         while b != Self::ZERO {
+            if b == -Self::ONE {
+                return b;
+            }
             let t = b;
             b = a % b;
             a = t;
         }
         if a == Self::ZERO {
-            Self::ONE
+            -Self::ONE
         } else {
             a
         }
     }
+
+    /// Divides both by their gcd. Signs may come out flipped together.
+    fn reduce_pair((a, b): (Self, Self)) -> (Self, Self) {
+        let g = Self::neg_gcd(a, b);
+        if g == -Self::ONE {
+            (a, b)
+        } else {
+            (a / g, b / g)
+        }
+    }
+}
+
+// Overflow-aware pair operations shared by the integer rings.
+// A result that cannot be represented becomes `BOTTOM`.
+
+fn integer_normalize_pair<T: Gcd + CheckedRing>(pair: (T, T)) -> (T, T) {
+    let (a, b) = T::reduce_pair(pair);
+    if b < T::ZERO {
+        match (a.checked_neg(), b.checked_neg()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => (T::ZERO, T::ZERO),
+        }
+    } else {
+        (a, b)
+    }
+}
+
+/// `a/b + c/d`, or `a/b - c/d` when `subtract` is set.
+fn integer_combine_pairs<T: Gcd + CheckedRing>((a, b): (T, T), (c, d): (T, T), subtract: bool) -> (T, T) {
+    let combine = |x: T, y: T| if subtract { x.checked_sub(y) } else { x.checked_add(y) };
+    let direct = || combine(a.checked_mul(d)?, b.checked_mul(c)?);
+    if let (Some(numerator), Some(denominator)) = (direct(), b.checked_mul(d)) {
+        return (numerator, denominator);
+    }
+    if b <= T::ZERO || d <= T::ZERO {
+        return (T::ZERO, T::ZERO);
+    }
+    // Knuth's method: divide out the common part of the denominators first.
+    let g = T::gcd(b, d);
+    let sum = || combine(a.checked_mul(d / g)?, c.checked_mul(b / g)?);
+    let Some(numerator) = sum() else {
+        return (T::ZERO, T::ZERO);
+    };
+    let h = T::gcd(numerator, g);
+    match (b / g).checked_mul(d / h) {
+        Some(denominator) => (numerator / h, denominator),
+        None => (T::ZERO, T::ZERO),
+    }
+}
+
+/// `integer_combine_pairs`, falling back to the wider type when the result overflows.
+/// Knuth's method alone can overflow on a numerator that only fits once
+/// reduced; in the wider type nothing overflows, so the result is exact.
+fn widening_combine_pairs<T>(x: (T, T), y: (T, T), subtract: bool) -> (T, T)
+where
+    T: Gcd + CheckedRing + Widen + TryFrom<WideOf<T>>,
+    WideOf<T>: Gcd + CheckedRing,
+{
+    let ((a, b), (c, d)) = (x, y);
+    let combine = |x: T, y: T| if subtract { x.checked_sub(y) } else { x.checked_add(y) };
+    let direct = || combine(a.checked_mul(d)?, b.checked_mul(c)?);
+    if let (Some(numerator), Some(denominator)) = (direct(), b.checked_mul(d)) {
+        return (numerator, denominator);
+    }
+    let widen = |(a, b): (T, T)| (WideOf::<T>::from(a), WideOf::<T>::from(b));
+    let (numerator, denominator) = integer_normalize_pair(integer_combine_pairs(widen(x), widen(y), subtract));
+    match (T::try_from(numerator), T::try_from(denominator)) {
+        (Ok(numerator), Ok(denominator)) => (numerator, denominator),
+        _ => (T::ZERO, T::ZERO),
+    }
+}
+
+/// Orders `a/b` and `c/d` for positive `b` and `d` without overflowing:
+/// cross-multiplies when that fits, and otherwise compares the continued
+/// fraction expansions term by term.
+fn integer_cmp_pairs<T: Gcd + CheckedRing>((a, b): (T, T), (c, d): (T, T)) -> Ordering {
+    if let (Some(left), Some(right)) = (a.checked_mul(d), c.checked_mul(b)) {
+        return left.cmp(&right);
+    }
+    // Invariant: the answer is `x.cmp(y)` for `x = a/b`, `y = c/d`, both positive denominators.
+    let ((mut a, mut b), (mut c, mut d)) = ((a, b), (c, d));
+    loop {
+        let (p, r) = floor_div_rem(a, b);
+        let (q, s) = floor_div_rem(c, d);
+        if p != q {
+            return p.cmp(&q);
+        }
+        match (r == T::ZERO, s == T::ZERO) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            // a/b <=> c/d is r/b <=> s/d, which is d/s <=> b/r.
+            (false, false) => ((a, b), (c, d)) = ((d, s), (b, r)),
+        }
+    }
+}
+
+fn integer_mul_pairs<T: Gcd + CheckedRing>((a, b): (T, T), (c, d): (T, T)) -> (T, T) {
+    if let (Some(numerator), Some(denominator)) = (a.checked_mul(c), b.checked_mul(d)) {
+        return (numerator, denominator);
+    }
+    // Cancel across the diagonals first. `reduce_pair` may flip both signs, which
+    // would move a `MIN` numerator's sign onto the other factor; flip them back
+    // so the denominators stay positive.
+    let cancel = |pair: (T, T)| {
+        let (numerator, denominator) = T::reduce_pair(pair);
+        if denominator < T::ZERO {
+            Some((numerator.checked_neg()?, denominator.checked_neg()?))
+        } else {
+            Some((numerator, denominator))
+        }
+    };
+    let product = || {
+        let (a, d) = cancel((a, d))?;
+        let (c, b) = cancel((c, b))?;
+        Some((a.checked_mul(c)?, b.checked_mul(d)?))
+    };
+    product().unwrap_or((T::ZERO, T::ZERO))
+}
+
+fn integer_neg_pair<T: CheckedRing>((a, b): (T, T)) -> (T, T) {
+    match a.checked_neg() {
+        Some(a) => (a, b),
+        None => (T::ZERO, T::ZERO),
+    }
+}
+
+/// Ring operations that report overflow instead of wrapping or panicking.
+pub trait CheckedRing: Ring {
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+}
+
+impl CheckedRing for i8 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i8::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i8::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i8::checked_mul(self, other)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        i8::checked_neg(self)
+    }
+}
+
+impl CheckedRing for i16 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i16::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i16::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i16::checked_mul(self, other)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        i16::checked_neg(self)
+    }
+}
+
+impl CheckedRing for i32 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i32::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i32::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i32::checked_mul(self, other)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        i32::checked_neg(self)
+    }
+}
+
+impl CheckedRing for i64 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i64::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i64::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i64::checked_mul(self, other)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        i64::checked_neg(self)
+    }
+}
+
+impl CheckedRing for i128 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i128::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i128::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i128::checked_mul(self, other)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        i128::checked_neg(self)
+    }
 }
 
 impl Gcd for i8 {}
@@ -72,9 +349,37 @@ impl Ring for i8 {
     const ZERO: i8 = 0;
     const ONE: i8 = 1;
 
-    fn normalize_pair((a, b): (Self, Self)) -> (Self, Self) {
-        let gcd = Self::gcd(a, b);
-        (a / gcd, b / gcd)
+    fn compare_normalized_pairs(a: (Self, Self), b: (Self, Self)) -> bool {
+        a == b
+    }
+
+    fn cmp_pairs(a: (Self, Self), b: (Self, Self)) -> Option<Ordering> {
+        Some(integer_cmp_pairs(a, b))
+    }
+
+    fn add_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        widening_combine_pairs(a, b, false)
+    }
+
+    fn sub_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        widening_combine_pairs(a, b, true)
+    }
+
+    fn mul_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        integer_mul_pairs(a, b)
+    }
+
+    fn neg_pair(a: (Self, Self)) -> (Self, Self) {
+        integer_neg_pair(a)
+    }
+
+    fn normalize_pair(pair: (Self, Self)) -> (Self, Self) {
+        integer_normalize_pair(pair)
+    }
+
+    fn from_f64_pair(value: f64) -> (Self, Self) {
+        let value = FractionWheel::<Self>::from_f64(value);
+        (value.0, value.1)
     }
 }
 
@@ -82,9 +387,37 @@ impl Ring for i16 {
     const ZERO: i16 = 0;
     const ONE: i16 = 1;
 
-    fn normalize_pair((a, b): (Self, Self)) -> (Self, Self) {
-        let gcd = Self::gcd(a, b);
-        (a / gcd, b / gcd)
+    fn compare_normalized_pairs(a: (Self, Self), b: (Self, Self)) -> bool {
+        a == b
+    }
+
+    fn cmp_pairs(a: (Self, Self), b: (Self, Self)) -> Option<Ordering> {
+        Some(integer_cmp_pairs(a, b))
+    }
+
+    fn add_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        widening_combine_pairs(a, b, false)
+    }
+
+    fn sub_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        widening_combine_pairs(a, b, true)
+    }
+
+    fn mul_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        integer_mul_pairs(a, b)
+    }
+
+    fn neg_pair(a: (Self, Self)) -> (Self, Self) {
+        integer_neg_pair(a)
+    }
+
+    fn normalize_pair(pair: (Self, Self)) -> (Self, Self) {
+        integer_normalize_pair(pair)
+    }
+
+    fn from_f64_pair(value: f64) -> (Self, Self) {
+        let value = FractionWheel::<Self>::from_f64(value);
+        (value.0, value.1)
     }
 }
 
@@ -92,9 +425,37 @@ impl Ring for i32 {
     const ZERO: i32 = 0;
     const ONE: i32 = 1;
 
-    fn normalize_pair((a, b): (Self, Self)) -> (Self, Self) {
-        let gcd = Self::gcd(a, b);
-        (a / gcd, b / gcd)
+    fn compare_normalized_pairs(a: (Self, Self), b: (Self, Self)) -> bool {
+        a == b
+    }
+
+    fn cmp_pairs(a: (Self, Self), b: (Self, Self)) -> Option<Ordering> {
+        Some(integer_cmp_pairs(a, b))
+    }
+
+    fn add_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        widening_combine_pairs(a, b, false)
+    }
+
+    fn sub_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        widening_combine_pairs(a, b, true)
+    }
+
+    fn mul_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        integer_mul_pairs(a, b)
+    }
+
+    fn neg_pair(a: (Self, Self)) -> (Self, Self) {
+        integer_neg_pair(a)
+    }
+
+    fn normalize_pair(pair: (Self, Self)) -> (Self, Self) {
+        integer_normalize_pair(pair)
+    }
+
+    fn from_f64_pair(value: f64) -> (Self, Self) {
+        let value = FractionWheel::<Self>::from_f64(value);
+        (value.0, value.1)
     }
 }
 
@@ -102,9 +463,37 @@ impl Ring for i64 {
     const ZERO: i64 = 0;
     const ONE: i64 = 1;
 
-    fn normalize_pair((a, b): (Self, Self)) -> (Self, Self) {
-        let gcd = Self::gcd(a, b);
-        (a / gcd, b / gcd)
+    fn compare_normalized_pairs(a: (Self, Self), b: (Self, Self)) -> bool {
+        a == b
+    }
+
+    fn cmp_pairs(a: (Self, Self), b: (Self, Self)) -> Option<Ordering> {
+        Some(integer_cmp_pairs(a, b))
+    }
+
+    fn add_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        widening_combine_pairs(a, b, false)
+    }
+
+    fn sub_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        widening_combine_pairs(a, b, true)
+    }
+
+    fn mul_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        integer_mul_pairs(a, b)
+    }
+
+    fn neg_pair(a: (Self, Self)) -> (Self, Self) {
+        integer_neg_pair(a)
+    }
+
+    fn normalize_pair(pair: (Self, Self)) -> (Self, Self) {
+        integer_normalize_pair(pair)
+    }
+
+    fn from_f64_pair(value: f64) -> (Self, Self) {
+        let value = FractionWheel::<Self>::from_f64(value);
+        (value.0, value.1)
     }
 }
 
@@ -112,12 +501,75 @@ impl Ring for i128 {
     const ZERO: i128 = 0;
     const ONE: i128 = 1;
 
-    fn normalize_pair((a, b): (Self, Self)) -> (Self, Self) {
-        let gcd = Self::gcd(a, b);
-        (a / gcd, b / gcd)
+    fn compare_normalized_pairs(a: (Self, Self), b: (Self, Self)) -> bool {
+        a == b
+    }
+
+    fn cmp_pairs(a: (Self, Self), b: (Self, Self)) -> Option<Ordering> {
+        Some(integer_cmp_pairs(a, b))
     }
+
+    fn add_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        integer_combine_pairs(a, b, false)
+    }
+
+    fn sub_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        integer_combine_pairs(a, b, true)
+    }
+
+    fn mul_pairs(a: (Self, Self), b: (Self, Self)) -> (Self, Self) {
+        integer_mul_pairs(a, b)
+    }
+
+    fn neg_pair(a: (Self, Self)) -> (Self, Self) {
+        integer_neg_pair(a)
+    }
+
+    fn normalize_pair(pair: (Self, Self)) -> (Self, Self) {
+        integer_normalize_pair(pair)
+    }
+
+    fn from_f64_pair(value: f64) -> (Self, Self) {
+        let value = FractionWheel::<Self>::from_f64(value);
+        (value.0, value.1)
+    }
+}
+
+/// Links an integer type to its wider sibling, used for overflow promotion.
+pub trait Widen: Ring {
+    type Wide: Ring + From<Self>;
+}
+
+/// The wider sibling of `T`.
+pub type WideOf<T> = <T as Widen>::Wide;
+
+impl Widen for i8 {
+    type Wide = i16;
+}
+
+impl Widen for i16 {
+    type Wide = i32;
+}
+
+impl Widen for i32 {
+    type Wide = i64;
+}
+
+impl Widen for i64 {
+    type Wide = i128;
 }
 
+/// One of two values; `FractionWheel::compute_wide` returns a result in either
+/// the narrow or the wide fraction type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// A fraction `numerator / denominator`, always kept normalized.
+/// With the built-in integer types, intermediate overflow is avoided where
+/// possible, and a result that cannot be represented in `T` becomes `BOTTOM`.
 #[derive(Debug, Clone, Copy)]
 pub struct FractionWheel<T: Ring> (T, T);
 
@@ -136,6 +588,13 @@ impl<T: Ring> FractionWheel<T> {
         value.normalize()
     }
 
+    /// Builds a value without normalizing it, for constants known to be normalized.
+    /// Equality and ordering assume normalized values, so this is not public;
+    /// build unreduced values as `UnreducedFraction` instead.
+    pub(crate) const fn new_unnormalized(numerator: T, denominator: T) -> Self {
+        FractionWheel(numerator, denominator)
+    }
+
     fn normalize(&self) -> Self {
         let (numerator, denominator) = T::normalize_pair((self.0, self.1));
         if denominator < T::ZERO {
@@ -148,27 +607,32 @@ impl<T: Ring> FractionWheel<T> {
     }
 
     fn add(&self, other: Self) -> Self {
-        let a = self.0 * other.1;
-        let b = self.1 * other.0;
-        let c = self.1 * other.1;
-        FractionWheel(a + b, c).normalize()
+        let (numerator, denominator) = T::add_pairs((self.0, self.1), (other.0, other.1));
+        FractionWheel(numerator, denominator).normalize()
     }
 
     fn neg(&self) -> Self {
-        FractionWheel(-self.0, self.1).normalize()
+        let (numerator, denominator) = T::neg_pair((self.0, self.1));
+        FractionWheel(numerator, denominator).normalize()
     }
 
     /// Defined as `self + other.neg()`.
     /// `x - x` is not always zero.
     fn sub(&self, other: Self) -> Self {
-        self.add(other.neg())
+        let (numerator, denominator) = T::sub_pairs((self.0, self.1), (other.0, other.1));
+        FractionWheel(numerator, denominator).normalize()
+    }
+
+    /// `self * k`, reduced, with `BOTTOM` on overflow like `mul`.
+    /// `INFINITY` stays `INFINITY` unless `k` is zero, which gives `BOTTOM`.
+    pub fn times_scalar_int(&self, k: T) -> Self {
+        self.mul(FractionWheel(k, T::ONE))
     }
 
     /// `0 * x` is not always zero.
     fn mul(&self, other: Self) -> Self {
-        let a = self.0 * other.0;
-        let b = self.1 * other.1;
-        FractionWheel(a, b).normalize()
+        let (numerator, denominator) = T::mul_pairs((self.0, self.1), (other.0, other.1));
+        FractionWheel(numerator, denominator).normalize()
     }
 
     /// Always defined. Not the same as the multiplicative inverse.
@@ -178,405 +642,2858 @@ impl<T: Ring> FractionWheel<T> {
 
     /// Always defined as `self * other.inv()`.
     /// `x / x` is not always one
+    ///
+    /// The swapped components go into the product unnormalized, so an `i32::MIN`
+    /// numerator in `other` does not have to be negated on its own first.
     fn div(&self, other: Self) -> Self {
-        self.mul(other.inv())
+        let (numerator, denominator) = T::mul_pairs((self.0, self.1), (other.1, other.0));
+        FractionWheel(numerator, denominator).normalize()
     }
 
-    fn eq(&self, other: Self) -> bool {
-        T::compare_pairs((self.0, self.1), (other.0, other.1))
+    /// Applies `f` to the components `(a, b, c, d)` of `a/b` and `c/d`,
+    /// and normalizes the returned `(numerator, denominator)` pair.
+    /// An escape hatch for custom rational operations.
+    pub fn combine_with<F: Fn(T, T, T, T) -> (T, T)>(&self, other: &Self, f: F) -> Self {
+        let (numerator, denominator) = f(self.0, self.1, other.0, other.1);
+        FractionWheel(numerator, denominator).normalize()
     }
-}
 
-impl<T: Ring> Wheel for FractionWheel<T> {
-    const ZERO: Self = FractionWheel::ZERO;
-    const ONE: Self = FractionWheel::ONE;
-    const INFINITY: Self = FractionWheel::INFINITY;
-    const BOTTOM: Self = FractionWheel::BOTTOM;
+    /// Absolute value. `INFINITY` and `BOTTOM` are unsigned and stay as they are.
+    pub fn abs(&self) -> Self {
+        if self.0 < T::ZERO {
+            self.neg()
+        } else {
+            *self
+        }
+    }
 
-    fn add(&self, other: &Self) -> Self {
-        FractionWheel::add(self, *other)
+    /// The sign of `self`; `ZERO`, `INFINITY` and `BOTTOM` each get their own variant.
+    pub fn sign(&self) -> Sign {
+        match self.classify() {
+            WheelClass::Zero => Sign::Zero,
+            WheelClass::Infinity => Sign::Infinite,
+            WheelClass::Bottom => Sign::Bottom,
+            WheelClass::Normal if self.0 < T::ZERO => Sign::Negative,
+            WheelClass::Normal => Sign::Positive,
+        }
     }
 
-    fn neg(&self) -> Self {
-        FractionWheel::neg(self)
+    /// Splits into the sign and the non-negative magnitude `abs()`.
+    /// `ZERO`, `INFINITY` and `BOTTOM` are their own magnitude.
+    pub fn into_sign_magnitude(self) -> (Sign, Self) {
+        (self.sign(), self.abs())
     }
 
-    fn mul(&self, other: &Self) -> Self {
-        FractionWheel::mul(self, *other)
+    /// `|self - other|`. `INFINITY` against a finite value gives `INFINITY`;
+    /// `INFINITY` against `INFINITY`, or any `BOTTOM`, gives `BOTTOM`.
+    pub fn abs_sub(&self, other: &Self) -> Self {
+        self.sub(*other).abs()
     }
 
-    fn inv(&self) -> Self {
-        FractionWheel::inv(self)
+    /// True for finite whole numbers. `INFINITY` and `BOTTOM` are not integers.
+    pub fn is_integer(&self) -> bool {
+        self.1 == T::ONE
     }
-}
 
+    /// The value as an integer, or `None` if it is not one.
+    pub fn to_integer(&self) -> Option<T> {
+        if self.is_integer() {
+            Some(self.0)
+        } else {
+            None
+        }
+    }
 
-// Conversion from integers
+    /// `n / Σ(1/xᵢ)`. An `INFINITY` element contributes nothing to the sum,
+    /// a `ZERO` element makes the mean `ZERO`, and any `BOTTOM` gives `BOTTOM`.
+    /// The mean of an empty slice is `BOTTOM`.
+    pub fn harmonic_mean(values: &[Self]) -> Self {
+        let n: Self = values.iter().map(|_| Self::ONE).sum();
+        let reciprocal_sum: Self = values.iter().map(|x| x.inv()).sum();
+        n.div(reciprocal_sum)
+    }
 
-impl From<i8> for FractionWheel<i8> {
-    fn from(value: i8) -> Self {
-        FractionWheel(value, 1)
+    /// The middle value, or the exact mean of the two middle values for an
+    /// even count. Sorts `values` in place. `INFINITY` has no place in the
+    /// order, so it gives `BOTTOM`, as do `BOTTOM` and an empty slice.
+    pub fn median(values: &mut [Self]) -> Self {
+        if values.is_empty() || values.iter().any(|x| x.1 == T::ZERO) {
+            return Self::BOTTOM;
+        }
+        values.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        let middle = values.len() / 2;
+        if values.len() % 2 == 1 {
+            values[middle]
+        } else {
+            let two = FractionWheel(T::ONE + T::ONE, T::ONE);
+            values[middle - 1].add(values[middle]).div(two)
+        }
     }
-}
 
-impl From<i16> for FractionWheel<i16> {
-    fn from(value: i16) -> Self {
-        FractionWheel(value, 1)
+    /// `1 / Σ(1/xᵢ)`, e.g. resistors in parallel. An `INFINITY` element (open)
+    /// drops out, and a single `ZERO` element (short) makes the result `ZERO`;
+    /// two `ZERO` elements give `BOTTOM`, since `INFINITY + INFINITY` is `BOTTOM`.
+    /// An empty slice gives `INFINITY`.
+    pub fn reciprocal_sum(values: &[Self]) -> Self {
+        let sum: Self = values.iter().map(|x| x.inv()).sum();
+        sum.inv()
     }
-}
 
-impl From<i32> for FractionWheel<i32> {
-    fn from(value: i32) -> Self {
-        FractionWheel(value, 1)
+    /// Values are always kept normalized, so this avoids cross-multiplying.
+    fn eq(&self, other: Self) -> bool {
+        T::compare_normalized_pairs((self.0, self.1), (other.0, other.1))
     }
 }
 
-impl From<i64> for FractionWheel<i64> {
-    fn from(value: i64) -> Self {
-        FractionWheel(value, 1)
+impl<T: Gcd> FractionWheel<T> {
+    /// Builds a value from parts the caller guarantees are already normalized:
+    /// in lowest terms with a non-negative denominator, as `new` would produce
+    /// (`0/1`, `1/0` and `0/0` for the specials). Skips the gcd.
+    ///
+    /// This is checked with `debug_assert!` only. In release builds, parts that
+    /// are not normalized give a value that compares wrongly with `==`.
+    pub fn from_parts_reduced(numerator: T, denominator: T) -> Self {
+        debug_assert!(
+            denominator >= T::ZERO
+                && (T::neg_gcd(numerator, denominator) == -T::ONE
+                    || (numerator == T::ZERO && denominator == T::ZERO)),
+            "{:?}/{:?} is not reduced",
+            numerator,
+            denominator,
+        );
+        FractionWheel(numerator, denominator)
     }
-}
 
-impl From<i128> for FractionWheel<i128> {
-    fn from(value: i128) -> Self {
-        FractionWheel(value, 1)
+    /// True when the reduced denominator is a power of two, so that `self` is
+    /// exactly representable in binary floating point given enough precision.
+    /// Integers, `ZERO` included, are dyadic; `INFINITY` and `BOTTOM` are not.
+    pub fn is_dyadic(&self) -> bool {
+        let two = T::ONE + T::ONE;
+        let mut denominator = self.1;
+        if denominator == T::ZERO {
+            return false;
+        }
+        while denominator % two == T::ZERO {
+            denominator = denominator / two;
+        }
+        denominator == T::ONE
     }
-}
 
+    /// `⌊self / other⌋` as an integer, or `None` if the quotient is `INFINITY` or `BOTTOM`.
+    pub fn div_floor(&self, other: &Self) -> Option<T> {
+        let FractionWheel(numerator, denominator) = self.div(*other);
+        if denominator == T::ZERO {
+            return None;
+        }
+        let quotient = numerator / denominator;
+        if numerator % denominator != T::ZERO && numerator < T::ZERO {
+            Some(quotient - T::ONE)
+        } else {
+            Some(quotient)
+        }
+    }
 
-// Arithmetic operators
+    /// `⌈self / other⌉` as an integer, or `None` if the quotient is `INFINITY` or `BOTTOM`.
+    pub fn div_ceil(&self, other: &Self) -> Option<T> {
+        let FractionWheel(numerator, denominator) = self.div(*other);
+        if denominator == T::ZERO {
+            return None;
+        }
+        let quotient = numerator / denominator;
+        if numerator % denominator != T::ZERO && numerator > T::ZERO {
+            Some(quotient + T::ONE)
+        } else {
+            Some(quotient)
+        }
+    }
 
-// Add
+    /// The bit lengths of the magnitudes of the normalized numerator and denominator.
+    /// Zero components take 0 bits, so `INFINITY` is `(1, 0)` and `BOTTOM` is `(0, 0)`.
+    pub fn component_bits(&self) -> (u32, u32) {
+        (bit_length(self.0), bit_length(self.1))
+    }
 
-impl<T: Ring> Add for FractionWheel<T> {
-    type Output = Self;
+    /// The larger of the two `component_bits`; an operation on values with
+    /// `needed_bits` close to the width of `T` is likely to overflow.
+    pub fn needed_bits(&self) -> u32 {
+        let (numerator, denominator) = self.component_bits();
+        numerator.max(denominator)
+    }
 
-    fn add(self, other: Self) -> Self {
-        Self::add(&self, other)
+    /// The integer part, truncated toward zero, and the remainder, which has the
+    /// sign of `self`: `7/2` gives `(3, 1/2)` and `-7/2` gives `(-3, -1/2)`.
+    /// `INFINITY` and `BOTTOM` give `(0, self)`.
+    pub fn split_integer_fraction(&self) -> (T, Self) {
+        let FractionWheel(numerator, denominator) = *self;
+        if denominator == T::ZERO {
+            return (T::ZERO, *self);
+        }
+        let remainder = numerator % denominator;
+        let fraction = if remainder == T::ZERO { Self::ZERO } else { FractionWheel(remainder, denominator) };
+        (numerator / denominator, fraction)
+    }
+
+    /// Formats as a mixed number such as `1 1/2` or `-1 1/2`.
+    /// Integers print as just the number; `INFINITY` and `BOTTOM` print as `Inf` and `Bottom`.
+    pub fn fmt_mixed(&self) -> MixedNumber<T> {
+        MixedNumber(*self)
     }
 }
 
-impl<T: Ring> Add<&FractionWheel<T>> for FractionWheel<T> {
-    type Output = FractionWheel<T>;
+/// A `numerator / denominator` pair that is not kept reduced, so the fields can
+/// be set and updated freely, e.g. building many values and reducing once at the end.
+/// `reduce` turns it into a `FractionWheel`. The derived `==` compares the raw fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnreducedFraction<T: Ring> {
+    pub numerator: T,
+    pub denominator: T,
+}
 
-    fn add(self, other: &Self) -> Self {
-        Self::add(&self, *other)
+impl<T: Ring> UnreducedFraction<T> {
+    pub const fn new(numerator: T, denominator: T) -> Self {
+        UnreducedFraction { numerator, denominator }
+    }
+
+    /// The normalized value, as `FractionWheel::new` would produce.
+    pub fn reduce(&self) -> FractionWheel<T> {
+        FractionWheel::new(self.numerator, self.denominator)
+    }
+
+    /// Replaces the fields with their normalized form.
+    pub fn reduce_in_place(&mut self) {
+        let FractionWheel(numerator, denominator) = self.reduce();
+        (self.numerator, self.denominator) = (numerator, denominator);
+    }
+
+    /// Normalizes every value in place.
+    pub fn reduce_slice(values: &mut [Self]) {
+        for value in values.iter_mut() {
+            value.reduce_in_place();
+        }
     }
 }
 
-impl<T: Ring> Add<FractionWheel<T>> for &FractionWheel<T> {
-    type Output = FractionWheel<T>;
+impl<T: Ring> From<UnreducedFraction<T>> for FractionWheel<T> {
+    fn from(value: UnreducedFraction<T>) -> Self {
+        value.reduce()
+    }
+}
 
-    fn add(self, other: FractionWheel<T>) -> FractionWheel<T> {
-        FractionWheel::add(self, other)
+/// Mixed-number formatting of a `FractionWheel`, see `FractionWheel::fmt_mixed`.
+#[derive(Debug, Clone, Copy)]
+pub struct MixedNumber<T: Ring>(FractionWheel<T>);
+
+impl<T: Gcd + Display> Display for MixedNumber<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let FractionWheel(numerator, denominator) = self.0;
+        if denominator == T::ZERO {
+            return if numerator == T::ZERO { write!(f, "Bottom") } else { write!(f, "Inf") };
+        }
+        let (integer, FractionWheel(remainder, denominator)) = self.0.split_integer_fraction();
+        if remainder == T::ZERO {
+            write!(f, "{}", integer)
+        } else if integer == T::ZERO {
+            write!(f, "{}/{}", remainder, denominator)
+        } else {
+            write!(f, "{} {}/{}", integer, remainder.abs(), denominator)
+        }
     }
 }
 
-impl<T: Ring> Add<&FractionWheel<T>> for &FractionWheel<T> {
-    type Output = FractionWheel<T>;
+impl<T: CheckedRing + TryFrom<i64>> FractionWheel<T> {
+    /// The successive convergents of the continued fraction of `value`,
+    /// e.g. `3/1, 22/7, 333/106, 355/113, ...` for π. Stops when a term does
+    /// not fit in `T`, or when the expansion ends. The expansion is computed
+    /// in floating point, so very late terms carry its rounding error.
+    /// NaN and the infinities yield nothing.
+    pub fn convergents(value: f64) -> impl Iterator<Item = Self> {
+        let mut x = value;
+        let (mut p0, mut q0, mut p1, mut q1) = (T::ZERO, T::ONE, T::ONE, T::ZERO);
+        let mut done = !value.is_finite();
+        core::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            // 2^63: beyond this the term does not fit in `i64`.
+            if !(-9223372036854775808.0..9223372036854775808.0).contains(&x) {
+                return None;
+            }
+            let mut a = x as i64;
+            if a as f64 > x {
+                a -= 1;
+            }
+            let term = T::try_from(a).ok()?;
+            let p = term.checked_mul(p1)?.checked_add(p0)?;
+            let q = term.checked_mul(q1)?.checked_add(q0)?;
+            (p0, q0, p1, q1) = (p1, q1, p, q);
+            let fraction = x - a as f64;
+            if fraction == 0.0 {
+                done = true;
+            } else {
+                x = 1.0 / fraction;
+            }
+            Some(FractionWheel(p, q))
+        })
+    }
 
-    fn add(self, other: &FractionWheel<T>) -> FractionWheel<T> {
-        FractionWheel::add(self, *other)
+    /// `value` exactly, as the reduced `m / 2^k` or `m · 2^k` every finite `f64` is,
+    /// so `0.375` gives `3/8`. `None` if a component does not fit in `T`
+    /// (`0.1` needs a denominator of 2^55), or for NaN and the infinities.
+    pub fn from_dyadic(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        if value == 0.0 {
+            return Some(Self::ZERO);
+        }
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        // |value| = significand * 2^exponent
+        let (significand, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased_exponent - 1075)
+        };
+        let zeros = significand.trailing_zeros();
+        let (significand, exponent) = ((significand >> zeros) as i64, exponent + zeros as i32);
+        let numerator = T::try_from(if value < 0.0 { -significand } else { significand }).ok()?;
+        let power = checked_pow_component(T::ONE + T::ONE, exponent.unsigned_abs())?;
+        if exponent >= 0 {
+            Some(FractionWheel(numerator.checked_mul(power)?, T::ONE))
+        } else {
+            Some(FractionWheel(numerator, power))
+        }
     }
 }
 
-// Sub
+impl<T: CheckedRing + From<i8>> FractionWheel<T> {
+    /// Parses a decimal like `"-3.14"` exactly as `n / 10^k`, without going through floats.
+    /// Accepts an optional sign, and the fractional part may be omitted.
+    pub fn from_decimal_str(s: &str) -> Result<Self, WheelError> {
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (integer_part, fractional_part) = match digits.split_once('.') {
+            Some((integer_part, fractional_part)) => (integer_part, fractional_part),
+            None => (digits, ""),
+        };
+        if integer_part.is_empty() && fractional_part.is_empty() {
+            return Err(WheelError::InvalidFormat);
+        }
+        // Trailing zeros only inflate the denominator.
+        let fractional_part = fractional_part.trim_end_matches('0');
+        let ten = T::from(10);
+        let mut numerator = T::ZERO;
+        let mut denominator = T::ONE;
+        for (i, c) in integer_part.bytes().chain(fractional_part.bytes()).enumerate() {
+            if !c.is_ascii_digit() {
+                return Err(WheelError::InvalidFormat);
+            }
+            let digit = T::from((c - b'0') as i8);
+            numerator = numerator.checked_mul(ten).ok_or(WheelError::Overflow)?;
+            numerator = if negative {
+                numerator.checked_sub(digit)
+            } else {
+                numerator.checked_add(digit)
+            }.ok_or(WheelError::Overflow)?;
+            if i >= integer_part.len() {
+                denominator = denominator.checked_mul(ten).ok_or(WheelError::Overflow)?;
+            }
+        }
+        Ok(FractionWheel(numerator, denominator).normalize())
+    }
 
-impl<T: Ring> Sub for FractionWheel<T> {
-    type Output = Self;
+    /// Parses a decimal with a parenthesised repetend, like `"0.1(6)"` for `1/6`.
+    /// Strings without a repetend are handed to [`Self::from_decimal_str`].
+    pub fn from_repeating_decimal(s: &str) -> Result<Self, WheelError> {
+        let Some((head, repetend)) = s.split_once('(') else {
+            return Self::from_decimal_str(s);
+        };
+        let repetend = repetend.strip_suffix(')').ok_or(WheelError::InvalidFormat)?;
+        let (negative, head) = match head.as_bytes().first() {
+            Some(b'-') => (true, &head[1..]),
+            Some(b'+') => (false, &head[1..]),
+            _ => (false, head),
+        };
+        let (integer_part, fractional_part) = head.split_once('.').ok_or(WheelError::InvalidFormat)?;
+        if repetend.is_empty() {
+            return Err(WheelError::InvalidFormat);
+        }
+        // 0.1(6) = (166 - 16) / 900: shift the repetend past the point and subtract.
+        let ten = T::from(10);
+        let mut all = T::ZERO;
+        let mut head_value = T::ZERO;
+        let mut shift = T::ONE;
+        let mut period = T::ONE;
+        for (i, c) in integer_part.bytes().chain(fractional_part.bytes()).chain(repetend.bytes()).enumerate() {
+            if !c.is_ascii_digit() {
+                return Err(WheelError::InvalidFormat);
+            }
+            let digit = T::from((c - b'0') as i8);
+            all = all.checked_mul(ten).and_then(|a| a.checked_add(digit)).ok_or(WheelError::Overflow)?;
+            if i < integer_part.len() + fractional_part.len() {
+                head_value = all;
+                if i >= integer_part.len() {
+                    shift = shift.checked_mul(ten).ok_or(WheelError::Overflow)?;
+                }
+            } else {
+                period = period.checked_mul(ten).ok_or(WheelError::Overflow)?;
+            }
+        }
+        let mut numerator = all.checked_sub(head_value).ok_or(WheelError::Overflow)?;
+        if negative {
+            numerator = T::ZERO.checked_sub(numerator).ok_or(WheelError::Overflow)?;
+        }
+        let denominator = period.checked_sub(T::ONE)
+            .and_then(|p| p.checked_mul(shift))
+            .ok_or(WheelError::Overflow)?;
+        Ok(FractionWheel(numerator, denominator).normalize())
+    }
 
-    fn sub(self, other: Self) -> Self {
-        Self::sub(&self, other)
+    /// `self * 100`, exactly: `1/3` is `100/3` percent.
+    pub fn as_percent(&self) -> Self {
+        self.times_scalar_int(T::from(100))
+    }
+
+    /// `self * 1000`, exactly.
+    pub fn as_per_mille(&self) -> Self {
+        // 1000 does not fit in an `i8`.
+        self.as_percent().times_scalar_int(T::from(10))
     }
 }
 
-impl<T: Ring> Sub<&FractionWheel<T>> for FractionWheel<T> {
-    type Output = FractionWheel<T>;
+impl<T: CheckedRing> FractionWheel<T> {
+    /// `self` raised to `exp`, or `None` if a component overflows.
+    /// For negative exponents, raise `self.inv()`.
+    pub fn checked_pow(&self, exp: u32) -> Option<Self> {
+        Some(FractionWheel(checked_pow_component(self.0, exp)?, checked_pow_component(self.1, exp)?))
+    }
 
-    fn sub(self, other: &Self) -> Self {
-        Self::sub(&self, *other)
+    /// Integer power. Raises both components separately and normalizes once.
+    /// Negative exponents swap the components first.
+    /// A component that overflows `T` gives `BOTTOM`, as in the other operations.
+    pub fn pow(&self, exp: i32) -> Self {
+        let (numerator, denominator) = if exp < 0 { (self.1, self.0) } else { (self.0, self.1) };
+        let exp = exp.unsigned_abs();
+        match (checked_pow_component(numerator, exp), checked_pow_component(denominator, exp)) {
+            (Some(numerator), Some(denominator)) => FractionWheel(numerator, denominator).normalize(),
+            _ => Self::BOTTOM,
+        }
+    }
+
+    /// `ONE, self, self², …`, multiplied componentwise.
+    /// The iterator ends before the first power whose components overflow `T`.
+    pub fn powers(&self) -> impl Iterator<Item = Self> {
+        let base = *self;
+        core::iter::successors(Some(Self::ONE), move |power| {
+            Some(FractionWheel(power.0.checked_mul(base.0)?, power.1.checked_mul(base.1)?))
+        })
+    }
+
+    /// `(a·d, b·c)` for `self = a/b` and `other = c/d`, the two sides of the
+    /// cross-multiplied comparison `a/b <=> c/d`. `None` means a product overflows `T`.
+    pub fn cross_multiply(&self, other: &Self) -> Option<(T, T)> {
+        Some((self.0.checked_mul(other.1)?, self.1.checked_mul(other.0)?))
+    }
+
+    /// The sum of `values`, or `None` if an intermediate sum overflows.
+    /// `Sum` turns such an overflow into `BOTTOM` instead.
+    /// A `BOTTOM` coming from the values themselves is still `Some(BOTTOM)`.
+    pub fn checked_sum(values: &[Self]) -> Option<Self> {
+        values.iter().try_fold(Self::ZERO, |acc, x| {
+            let sum = acc.add(*x);
+            // Finite values never add up to `BOTTOM`, so this is an overflow.
+            if sum == Self::BOTTOM && acc.1 != T::ZERO && x.1 != T::ZERO {
+                None
+            } else {
+                Some(sum)
+            }
+        })
     }
 }
 
-impl<T: Ring> Sub<FractionWheel<T>> for &FractionWheel<T> {
-    type Output = FractionWheel<T>;
+impl<T: Gcd + CheckedRing> FractionWheel<T> {
+    /// The simplest fraction (smallest denominator) in the open interval `(lo, hi)`,
+    /// found by Stern–Brocot descent.
+    /// `INFINITY` as `lo` or `hi` means the interval is unbounded on that side.
+    /// Returns `BOTTOM` if either endpoint is `BOTTOM`, the interval is empty,
+    /// or the answer does not fit in `T`.
+    pub fn simplest_between(lo: &Self, hi: &Self) -> Self {
+        if *lo == Self::BOTTOM || *hi == Self::BOTTOM {
+            return Self::BOTTOM;
+        }
+        let lo_unbounded = *lo == Self::INFINITY;
+        let hi_unbounded = *hi == Self::INFINITY;
+        if !lo_unbounded && !hi_unbounded && lo >= hi {
+            return Self::BOTTOM;
+        }
+        let lo_negative = lo_unbounded || lo.0 < T::ZERO;
+        let hi_positive = hi_unbounded || hi.0 > T::ZERO;
+        let simplest = if lo_negative && hi_positive {
+            Some((T::ZERO, T::ONE))
+        } else if lo_negative {
+            // hi <= 0. Mirroring onto the positive side would negate `MIN`, so take
+            // c = ⌈hi⌉ directly: the answer is c - 1, or c - 1/s for the simplest s
+            // in (1/(c - lo), 1/(c - hi)).
+            let c = hi.0 / hi.1;
+            let c_minus_hi = -(hi.0 % hi.1);
+            let below = c.checked_sub(T::ONE);
+            match below {
+                None => None,
+                Some(below) if lo_unbounded || FractionWheel(below, T::ONE) > *lo => Some((below, T::ONE)),
+                Some(_) => {
+                    // lo lies in [c - 1, c), so c - lo is 1 - (its floor remainder).
+                    let (_, remainder) = floor_div_rem(lo.0, lo.1);
+                    Self::simplest_nonnegative(lo.1, lo.1 - remainder, hi.1, c_minus_hi)
+                        .and_then(|(n, m)| Some((c.checked_mul(n)?.checked_sub(m)?, n)))
+                }
+            }
+        } else {
+            let (hi_0, hi_1) = if hi_unbounded { (T::ONE, T::ZERO) } else { (hi.0, hi.1) };
+            Self::simplest_nonnegative(lo.0, lo.1, hi_0, hi_1)
+        };
+        match simplest {
+            Some((n, d)) => FractionWheel(n, d),
+            None => Self::BOTTOM,
+        }
+    }
+
+    /// Simplest fraction in `(a/b, c/d)` where `0 <= a/b < c/d`, `b > 0` and `d >= 0`.
+    /// `None` if it does not fit in `T`.
+    fn simplest_nonnegative(a: T, b: T, c: T, d: T) -> Option<(T, T)> {
+        let floor = a / b;
+        let next = floor.checked_add(T::ONE)?;
+        // An overflowing `next·d` exceeds `c`.
+        if d == T::ZERO || next.checked_mul(d).is_some_and(|next_d| next_d < c) {
+            return Some((next, T::ONE));
+        }
+        // Both bounds lie in [floor, floor + 1]: recurse on the reciprocals of the remainders.
+        let (n, m) = Self::simplest_nonnegative(d, c - floor * d, b, a - floor * b)?;
+        Some((floor.checked_mul(n)?.checked_add(m)?, n))
+    }
+
+    /// The closest fraction whose denominator is at most `max_denominator`,
+    /// found from the continued fraction expansion (convergents and semiconvergents).
+    /// `INFINITY` and `BOTTOM` are returned unchanged.
+    /// A `max_denominator` below one is treated as one.
+    pub fn limit_denominator(&self, max_denominator: T) -> Self {
+        let max_denominator = if max_denominator < T::ONE { T::ONE } else { max_denominator };
+        if self.1 == T::ZERO || self.1 <= max_denominator {
+            return *self;
+        }
+        // `x` and `y` track `|p·d - n·q|` for the previous and the last convergent,
+        // so the distances below never need the full cross products.
+        let (mut p0, mut q0, mut p1, mut q1) = (T::ZERO, T::ONE, T::ONE, T::ZERO);
+        let (mut x, mut y) = (self.0, self.1);
+        loop {
+            let (a, r) = floor_div_rem(x, y);
+            let next = || Some((p0.checked_add(a.checked_mul(p1)?)?, q0.checked_add(a.checked_mul(q1)?)?));
+            match next() {
+                Some((p2, q2)) if q2 <= max_denominator => {
+                    (p0, q0, p1, q1) = (p1, q1, p2, q2);
+                    (x, y) = (y, r);
+                }
+                _ => break,
+            }
+        }
+        // Best semiconvergent below the bound, versus the last convergent.
+        // They lie on opposite sides of `self`, at distances `y / (q1·d)` and `(x - k·y) / (q2·d)`.
+        let k = (max_denominator - q0) / q1;
+        let semiconvergent = || Some((p0.checked_add(k.checked_mul(p1)?)?, q0 + k * q1));
+        match semiconvergent() {
+            Some((p2, q2)) if integer_cmp_pairs((x - k * y, q2), (y, q1)) == Ordering::Less => {
+                FractionWheel(p2, q2)
+            }
+            _ => FractionWheel(p1, q1),
+        }
+    }
+
+    /// True when both values have the same best approximation with
+    /// denominator at most `max_denominator` (see `limit_denominator`).
+    pub fn roughly_eq_den(&self, other: &Self, max_denominator: T) -> bool {
+        self.limit_denominator(max_denominator) == other.limit_denominator(max_denominator)
+    }
+
+    /// Whether `self` and `other` are Farey neighbors, `|a·d - b·c| = 1`.
+    /// The determinant is reduced Euclid-style instead of multiplied out,
+    /// so large components cannot overflow. `INFINITY` and `BOTTOM` have no neighbors.
+    pub fn is_farey_neighbor(&self, other: &Self) -> bool {
+        let (mut a, mut b, mut c, mut d) = (self.0, self.1, other.0, other.1);
+        if b <= T::ZERO || d <= T::ZERO {
+            return false;
+        }
+        // Shifting both values by the same integer, or taking both reciprocals,
+        // keeps `|a·d - b·c|`, and `a/b` stays reduced.
+        loop {
+            let (k, a_rem) = floor_div_rem(a, b);
+            let (l, c_rem) = floor_div_rem(c, d);
+            // Neighbors are at most one apart, so their integer parts differ by at most one.
+            let Some(shift) = l.checked_sub(k) else {
+                return false;
+            };
+            let c_shifted = if shift == T::ZERO {
+                c_rem
+            } else if shift == T::ONE {
+                match c_rem.checked_add(d) {
+                    Some(c) => c,
+                    None => return false,
+                }
+            } else if shift == -T::ONE {
+                c_rem - d
+            } else {
+                return false;
+            };
+            if a_rem == T::ZERO {
+                // `b` is one here, so the determinant is `-c_shifted`.
+                return c_shifted == T::ONE || c_shifted == -T::ONE;
+            }
+            if c_shifted == T::ZERO {
+                return a_rem == T::ONE && d == T::ONE;
+            }
+            (a, b, c, d) = if c_shifted < T::ZERO {
+                (b, a_rem, -d, -c_shifted)
+            } else {
+                (b, a_rem, d, c_shifted)
+            };
+        }
+    }
+
+    /// `self · denominator` rounded to the nearest integer (halves away from zero),
+    /// i.e. the numerator over `denominator` for fixed-point export.
+    /// `None` for `INFINITY`, `BOTTOM`, or if the result does not fit in `T`.
+    pub fn numerator_over(&self, denominator: T) -> Option<T> {
+        if self.1 == T::ZERO {
+            return None;
+        }
+        let g = T::gcd(denominator, self.1);
+        let numerator = self.0.checked_mul(denominator / g)?;
+        let divisor = self.1 / g;
+        let quotient = numerator / divisor;
+        let remainder = (numerator % divisor).abs();
+        if remainder < divisor - remainder {
+            Some(quotient)
+        } else if numerator < T::ZERO {
+            quotient.checked_sub(T::ONE)
+        } else {
+            quotient.checked_add(T::ONE)
+        }
+    }
+
+    /// The least common multiple of the denominators, or `None` if it overflows.
+    /// `INFINITY` and `BOTTOM` have no denominator to align and are skipped;
+    /// with no finite values the result is `ONE`.
+    pub fn checked_common_denominator(values: &[Self]) -> Option<T> {
+        values.iter()
+            .filter(|x| x.1 != T::ZERO)
+            .try_fold(T::ONE, |lcm, x| (lcm / T::gcd(lcm, x.1)).checked_mul(x.1))
+    }
+
+    /// Like `checked_common_denominator`, but an overflow gives `ZERO`,
+    /// as the denominator of `BOTTOM`.
+    pub fn common_denominator(values: &[Self]) -> T {
+        Self::checked_common_denominator(values).unwrap_or(T::ZERO)
+    }
+
+    /// The largest rational `g` with `self / g` and `other / g` both integers,
+    /// `gcd(a, c) / lcm(b, d)` for `a/b` and `c/d`; so `3/4` and `1/6` give `1/12`.
+    /// Always nonnegative, and `ZERO` only for two `ZERO`s, since `gcd(0, x) = |x|`.
+    /// `INFINITY` or `BOTTOM` on either side, or an overflow, gives `BOTTOM`.
+    pub fn rational_gcd(&self, other: &Self) -> Self {
+        let (FractionWheel(a, b), FractionWheel(c, d)) = (*self, *other);
+        if b == T::ZERO || d == T::ZERO {
+            return Self::BOTTOM;
+        }
+        if a == T::ZERO && c == T::ZERO {
+            return Self::ZERO;
+        }
+        let numerator = T::neg_gcd(a, c).checked_neg();
+        let denominator = (b / T::gcd(b, d)).checked_mul(d);
+        match (numerator, denominator) {
+            // `gcd(a, c)` divides `a` and `c`, so it is coprime to `b`, `d` and their lcm.
+            (Some(numerator), Some(denominator)) => FractionWheel(numerator, denominator),
+            _ => Self::BOTTOM,
+        }
+    }
+
+    /// The smallest nonnegative rational that is an integer multiple of both,
+    /// `lcm(a, c) / gcd(b, d)`; so `3/4` and `1/6` give `3/2`.
+    /// `ZERO` on either side gives `ZERO`. `INFINITY` or `BOTTOM` on either side,
+    /// or an overflow, gives `BOTTOM`.
+    pub fn rational_lcm(&self, other: &Self) -> Self {
+        let (FractionWheel(a, b), FractionWheel(c, d)) = (*self, *other);
+        if b == T::ZERO || d == T::ZERO {
+            return Self::BOTTOM;
+        }
+        if a == T::ZERO || c == T::ZERO {
+            return Self::ZERO;
+        }
+        let magnitude = |x: T| if x < T::ZERO { x.checked_neg() } else { Some(x) };
+        let numerator = magnitude(a / T::gcd(a, c)).zip(magnitude(c)).and_then(|(x, y)| x.checked_mul(y));
+        match numerator {
+            Some(numerator) => FractionWheel(numerator, T::gcd(b, d)),
+            None => Self::BOTTOM,
+        }
+    }
+
+    /// Each value as a `(numerator, den)` pair. `den` should be a multiple of
+    /// every finite denominator, e.g. from `common_denominator`; a value that
+    /// cannot be rescaled exactly gives `(0, 0)`. `INFINITY` and `BOTTOM` keep their pairs.
+    #[cfg(feature = "alloc")]
+    pub fn to_common_denominator(values: &[Self], den: T) -> Vec<(T, T)> {
+        values.iter()
+            .map(|x| {
+                if x.1 == T::ZERO {
+                    return (x.0, x.1);
+                }
+                if den == T::ZERO || den % x.1 != T::ZERO {
+                    return (T::ZERO, T::ZERO);
+                }
+                match x.0.checked_mul(den / x.1) {
+                    Some(numerator) => (numerator, den),
+                    None => (T::ZERO, T::ZERO),
+                }
+            })
+            .collect()
+    }
+
+    /// `self` as an unreduced `(numerator, denominator)` pair over `denominator`:
+    /// `1/3` over `12` is `(4, 12)`. `None` unless `self`'s denominator divides
+    /// `denominator`, or if the numerator overflows; always `None` for `INFINITY` and `BOTTOM`.
+    pub fn expand_to(&self, denominator: T) -> Option<(T, T)> {
+        if self.1 == T::ZERO || denominator == T::ZERO || denominator % self.1 != T::ZERO {
+            return None;
+        }
+        Some((self.0.checked_mul(denominator / self.1)?, denominator))
+    }
+
+    /// A sum of distinct unit fractions equal to `self`, by the greedy
+    /// (Fibonacci–Sylvester) algorithm: `4/13` is `1/4 + 1/18 + 1/468`.
+    /// The integer part of an improper fraction comes first as `n/1`,
+    /// and a negative value gives the negated terms of its magnitude.
+    /// `ZERO` gives no terms. `None` for `INFINITY`, `BOTTOM`, or when a
+    /// denominator overflows `T`, which the greedy denominators do quickly.
+    #[cfg(feature = "alloc")]
+    pub fn to_egyptian(&self) -> Option<Vec<Self>> {
+        if self.1 == T::ZERO {
+            return None;
+        }
+        let (integer, fraction) = self.split_integer_fraction();
+        let mut terms = Vec::new();
+        if integer != T::ZERO {
+            terms.push(FractionWheel(integer, T::ONE));
+        }
+        let negative = fraction.0 < T::ZERO;
+        let unit = if negative { -T::ONE } else { T::ONE };
+        let (mut a, mut b) = (fraction.0.abs(), fraction.1);
+        while a != T::ZERO {
+            // The largest unit fraction not above `a/b` is `1/ceil(b/a)`.
+            let n = (b - T::ONE) / a + T::ONE;
+            terms.push(FractionWheel(unit, n));
+            if a == T::ONE {
+                break;
+            }
+            // `a·n` and `b·n` can overflow; once `a·n` fits, `a·n - b < a` does too.
+            let rest = FractionWheel(a.checked_mul(n)? - b, b.checked_mul(n)?).normalize();
+            (a, b) = (rest.0, rest.1);
+        }
+        Some(terms)
+    }
+
+    /// The exact geometric mean, or `None` when it is irrational or there are
+    /// more than `u32::MAX` values.
+    /// Specials follow the product: any `BOTTOM` gives `BOTTOM`, and
+    /// `ZERO` with `INFINITY` in the same slice is `BOTTOM` as well.
+    /// The mean of an empty slice is `BOTTOM`.
+    pub fn try_geometric_mean(values: &[Self]) -> Option<Self> {
+        let n = u32::try_from(values.len()).ok()?;
+        let product: Self = values.iter().product();
+        if n == 0 {
+            return Some(Self::BOTTOM);
+        }
+        product.try_root(n)
+    }
+
+    /// The exact square root, or `None` when it is irrational or `self` is negative.
+    /// `ZERO`, `INFINITY` and `BOTTOM` are their own roots.
+    pub fn try_sqrt(&self) -> Option<Self> {
+        self.try_root(2)
+    }
+
+    /// The exact cube root, or `None` when it is irrational.
+    /// Negative values have negative roots; the specials are their own roots.
+    pub fn try_cbrt(&self) -> Option<Self> {
+        self.try_root(3)
+    }
+
+    /// The exact `n`-th root for `n >= 1`; components of a reduced fraction
+    /// are coprime, so the root is rational only if both are perfect powers.
+    fn try_root(&self, n: u32) -> Option<Self> {
+        if self.1 == T::ZERO || self.0 == T::ZERO {
+            return Some(*self);
+        }
+        let negative = self.0 < T::ZERO;
+        if negative && n.is_multiple_of(2) {
+            return None;
+        }
+        let numerator = exact_root(if negative { self.0.checked_neg()? } else { self.0 }, n)?;
+        let denominator = exact_root(self.1, n)?;
+        Some(FractionWheel(if negative { -numerator } else { numerator }, denominator))
+    }
+}
+
+impl<T: Widen> FractionWheel<T> {
+    /// Converts to the wider fraction type. Always exact.
+    pub fn widen(&self) -> FractionWheel<WideOf<T>> {
+        FractionWheel(self.0.into(), self.1.into())
+    }
+
+    /// Addition computed in the wider type, so it never overflows.
+    pub fn add_promote(&self, other: &Self) -> FractionWheel<WideOf<T>> {
+        self.widen().add(other.widen())
+    }
+
+    /// Subtraction computed in the wider type, so it never overflows.
+    pub fn sub_promote(&self, other: &Self) -> FractionWheel<WideOf<T>> {
+        self.widen().sub(other.widen())
+    }
+
+    /// Multiplication computed in the wider type, so it never overflows.
+    pub fn mul_promote(&self, other: &Self) -> FractionWheel<WideOf<T>> {
+        self.widen().mul(other.widen())
+    }
+
+    /// Division computed in the wider type, so it never overflows.
+    pub fn div_promote(&self, other: &Self) -> FractionWheel<WideOf<T>> {
+        self.widen().div(other.widen())
+    }
+
+    /// Runs `narrow_op` in `T`, and if that overflows, `wide_op` on the widened
+    /// operands instead, so only results that need the wider type pay for it:
+    /// `compute_wide(&a, &b, |a, b| a * b, |a, b| a * b)`.
+    /// Overflow shows up as a `BOTTOM` result from operands that are not `BOTTOM`,
+    /// so a genuinely undefined result such as `0 · ∞` is retried too, and is
+    /// returned as the wide `BOTTOM`.
+    pub fn compute_wide<F, G>(a: &Self, b: &Self, narrow_op: F, wide_op: G) -> Either<Self, FractionWheel<WideOf<T>>>
+    where
+        F: FnOnce(&Self, &Self) -> Self,
+        G: FnOnce(&FractionWheel<WideOf<T>>, &FractionWheel<WideOf<T>>) -> FractionWheel<WideOf<T>>,
+    {
+        let narrow = narrow_op(a, b);
+        if narrow != Self::BOTTOM || *a == Self::BOTTOM || *b == Self::BOTTOM {
+            return Either::Left(narrow);
+        }
+        Either::Right(wide_op(&a.widen(), &b.widen()))
+    }
+}
+
+impl<T: CheckedRing + TryFrom<i64> + Into<i128>> FractionWheel<T> {
+    /// The first convergent of `value` that converts back to exactly `value`,
+    /// or the last one that fits in `T`; so `0.1` gives `1/10`.
+    /// NaN gives `BOTTOM` and the infinities give `INFINITY`.
+    /// A value too large for `T` gives `BOTTOM`.
+    pub fn from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            return Self::BOTTOM;
+        }
+        if value.is_infinite() {
+            return Self::INFINITY;
+        }
+        let mut best = Self::BOTTOM;
+        for convergent in Self::convergents(value) {
+            best = convergent;
+            if convergent.to_f64_round() == value {
+                break;
+            }
+        }
+        best
+    }
+}
+
+impl<T: Gcd + CheckedRing + TryFrom<i64> + Into<i128>> FractionWheel<T> {
+    /// The closest fraction to `value` with denominator at most `max_denominator`,
+    /// and the signed error `value - fraction`, so a caller can decide whether
+    /// to allow a larger denominator. Like `limit_denominator`, the candidates
+    /// are the convergents and semiconvergents of `value`, compared in `f64`.
+    /// A `value` that does not fit in `T` gives `BOTTOM`, and the error is NaN
+    /// whenever the fraction is not finite.
+    pub fn approximate_with_error(value: f64, max_denominator: T) -> (Self, f64) {
+        if value.is_nan() {
+            return (Self::BOTTOM, f64::NAN);
+        }
+        if value.is_infinite() {
+            return (Self::INFINITY, f64::NAN);
+        }
+        let max_denominator = if max_denominator < T::ONE { T::ONE } else { max_denominator };
+        let error = |x: &Self| value - x.to_f64_round();
+        let (mut previous, mut best) = (Self::INFINITY, Self::BOTTOM);
+        for convergent in Self::convergents(value) {
+            if convergent.1 > max_denominator {
+                // The best semiconvergent within the bound, versus the last convergent.
+                let k = (max_denominator - previous.1) / best.1;
+                let semiconvergent = || {
+                    Some(FractionWheel(
+                        k.checked_mul(best.0)?.checked_add(previous.0)?,
+                        k.checked_mul(best.1)?.checked_add(previous.1)?,
+                    ))
+                };
+                if let Some(candidate) = semiconvergent() {
+                    if error(&candidate).abs() < error(&best).abs() {
+                        best = candidate;
+                    }
+                }
+                break;
+            }
+            (previous, best) = (best, convergent);
+        }
+        (best, error(&best))
+    }
+}
+
+impl<T: Ring + Into<i128>> FractionWheel<T> {
+    /// The nearest `f64`, correctly rounded (ties to even) from the exact quotient.
+    /// Unlike converting both components and dividing, this rounds only once.
+    /// `INFINITY` and `BOTTOM` give `f64::INFINITY` and NaN.
+    pub fn to_f64_round(&self) -> f64 {
+        let numerator: i128 = self.0.into();
+        let denominator: i128 = self.1.into();
+        if denominator == 0 {
+            return if numerator == 0 { f64::NAN } else { f64::INFINITY };
+        }
+        if numerator == 0 {
+            return 0.0;
+        }
+        let magnitude = div_to_f64(numerator.unsigned_abs(), denominator.unsigned_abs());
+        if (numerator < 0) != (denominator < 0) { -magnitude } else { magnitude }
+    }
+
+    /// `numerator/denominator` with both written in `radix`, using lowercase
+    /// digits: `255/16` in radix 16 is `ff/10`. The specials print as `Inf` and `Bottom`.
+    ///
+    /// # Panics
+    ///
+    /// If `radix` is not in `2..=36`.
+    #[cfg(feature = "alloc")]
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36, got {}", radix);
+        let numerator: i128 = self.0.into();
+        let denominator: i128 = self.1.into();
+        if denominator == 0 {
+            return if numerator == 0 { "Bottom".into() } else { "Inf".into() };
+        }
+        let mut out = String::new();
+        if numerator < 0 {
+            out.push('-');
+        }
+        push_radix(&mut out, numerator.unsigned_abs(), radix);
+        out.push('/');
+        push_radix(&mut out, denominator.unsigned_abs(), radix);
+        out
+    }
+}
+
+/// Appends the digits of `value` in `radix`, most significant first.
+#[cfg(feature = "alloc")]
+fn push_radix(out: &mut String, mut value: u128, radix: u32) {
+    let start = out.len();
+    loop {
+        let digit = (value % radix as u128) as u32;
+        out.insert(start, char::from_digit(digit, radix).unwrap());
+        value /= radix as u128;
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// `a / b` for nonzero `a` and `b`, correctly rounded.
+/// Long division yields 53 bits plus a rounding bit, with the remainder as the sticky bit.
+fn div_to_f64(a: u128, b: u128) -> f64 {
+    let mut remainder = 0u128;
+    let mut quotient = 0u64;
+    let mut bits = 0;
+    // The weight of the quotient bit being produced: a's bits first, then fractional bits.
+    let mut weight = 127i32;
+    loop {
+        let next = if weight >= 0 { (a >> weight) & 1 } else { 0 };
+        // `remainder < b < 2^128`, and `b <= 2^127` for `i128` inputs, so this cannot overflow.
+        remainder = remainder << 1 | next;
+        let bit = remainder >= b;
+        if bit {
+            remainder -= b;
+        }
+        if bits > 0 || bit {
+            quotient = quotient << 1 | bit as u64;
+            bits += 1;
+            if bits == 54 {
+                break;
+            }
+        }
+        weight -= 1;
+    }
+    let rest_of_a = weight > 0 && a & ((1u128 << weight) - 1) != 0;
+    let sticky = remainder != 0 || rest_of_a;
+    let mut mantissa = quotient >> 1;
+    if quotient & 1 == 1 && (sticky || mantissa & 1 == 1) {
+        mantissa += 1;
+    }
+    // `mantissa` has its lowest bit at weight `weight + 1`.
+    let mut exponent = weight + 1;
+    if mantissa == 1 << 53 {
+        mantissa >>= 1;
+        exponent += 1;
+    }
+    f64::from_bits(((exponent + 1075) as u64) << 52 | (mantissa & ((1 << 52) - 1)))
+}
+
+/// `(⌊a / b⌋, a mod b)` for a positive `b`, with the remainder in `0..b`.
+fn floor_div_rem<T: Gcd>(a: T, b: T) -> (T, T) {
+    let quotient = a / b;
+    let remainder = a % b;
+    if remainder < T::ZERO {
+        (quotient - T::ONE, remainder + b)
+    } else {
+        (quotient, remainder)
+    }
+}
+
+/// The number of bits in `|x|`, computed on the non-positive side so it works for `MIN`.
+fn bit_length<T: Gcd>(x: T) -> u32 {
+    let two = T::ONE + T::ONE;
+    let mut x = if x > T::ZERO { -x } else { x };
+    let mut bits = 0;
+    while x != T::ZERO {
+        x = x / two;
+        bits += 1;
+    }
+    bits
+}
+
+fn checked_pow_component<T: CheckedRing>(base: T, exp: u32) -> Option<T> {
+    let mut base = base;
+    let mut exp = exp;
+    let mut result = T::ONE;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result.checked_mul(base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = base.checked_mul(base)?;
+        }
+    }
+    Some(result)
+}
+
+/// The `n`-th root of a non-negative `x`, if it is an integer.
+fn exact_root<T: Gcd + CheckedRing>(x: T, n: u32) -> Option<T> {
+    let two = T::ONE + T::ONE;
+    let mut lo = T::ZERO;
+    let mut hi = x;
+    while lo <= hi {
+        let mid = lo + (hi - lo) / two;
+        match checked_pow_component(mid, n) {
+            Some(power) if power == x => return Some(mid),
+            Some(power) if power < x => lo = mid + T::ONE,
+            _ => hi = mid - T::ONE,
+        }
+    }
+    None
+}
+
+impl<T: Ring> Wheel for FractionWheel<T> {
+    const ZERO: Self = FractionWheel::ZERO;
+    const ONE: Self = FractionWheel::ONE;
+    const INFINITY: Self = FractionWheel::INFINITY;
+    const BOTTOM: Self = FractionWheel::BOTTOM;
+
+    fn add(&self, other: &Self) -> Self {
+        FractionWheel::add(self, *other)
+    }
+
+    fn neg(&self) -> Self {
+        FractionWheel::neg(self)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        FractionWheel::mul(self, *other)
+    }
+
+    fn inv(&self) -> Self {
+        FractionWheel::inv(self)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        FractionWheel::sub(self, *other)
+    }
+
+    fn div(&self, other: &Self) -> Self {
+        FractionWheel::div(self, *other)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        let (numerator, denominator) = T::from_f64_pair(value);
+        FractionWheel(numerator, denominator)
+    }
+
+    fn is_close_to_integer(&self, _tol: f64) -> bool {
+        self.0 != T::ZERO && self.is_integer()
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == self.1 && self.1 != T::ZERO
+    }
+
+    fn scale_by_pow2(&self, k: i32) -> Self {
+        if self.0 == T::ZERO || self.1 == T::ZERO {
+            return *self;
+        }
+        let two = T::ONE + T::ONE;
+        let step = if k < 0 { FractionWheel(T::ONE, two) } else { FractionWheel(two, T::ONE) };
+        // Each step cancels a factor of two or grows a component, so an
+        // overflow to `BOTTOM` comes within twice the bit width of `T`.
+        let mut x = *self;
+        for _ in 0..k.unsigned_abs() {
+            x = x.mul(step);
+            if x == Self::BOTTOM {
+                break;
+            }
+        }
+        x
+    }
+}
+
+
+// Conversion from integers
+
+impl From<i8> for FractionWheel<i8> {
+    fn from(value: i8) -> Self {
+        FractionWheel(value, 1)
+    }
+}
+
+impl From<i16> for FractionWheel<i16> {
+    fn from(value: i16) -> Self {
+        FractionWheel(value, 1)
+    }
+}
+
+impl From<i32> for FractionWheel<i32> {
+    fn from(value: i32) -> Self {
+        FractionWheel(value, 1)
+    }
+}
+
+impl From<i64> for FractionWheel<i64> {
+    fn from(value: i64) -> Self {
+        FractionWheel(value, 1)
+    }
+}
+
+impl From<i128> for FractionWheel<i128> {
+    fn from(value: i128) -> Self {
+        FractionWheel(value, 1)
+    }
+}
+
+// Unsigned integers convert into the next wider fraction type, so they always fit.
+
+impl From<u8> for FractionWheel<i16> {
+    fn from(value: u8) -> Self {
+        FractionWheel(value.into(), 1)
+    }
+}
+
+impl From<u16> for FractionWheel<i32> {
+    fn from(value: u16) -> Self {
+        FractionWheel(value.into(), 1)
+    }
+}
+
+impl From<u32> for FractionWheel<i64> {
+    fn from(value: u32) -> Self {
+        FractionWheel(value.into(), 1)
+    }
+}
+
+impl From<u64> for FractionWheel<i128> {
+    fn from(value: u64) -> Self {
+        FractionWheel(value.into(), 1)
+    }
+}
+
+impl<T: CheckedRing + From<i8>> FromStr for FractionWheel<T> {
+    type Err = WheelError;
+
+    /// Parses `"n/d"`, a decimal as in `from_decimal_str`, `"Inf"` or `"Bottom"`.
+    /// The Unicode fraction slash `⁄` may be used for `/`, and a vulgar fraction
+    /// such as `½` or `-¾` may be given on its own.
+    fn from_str(s: &str) -> Result<Self, WheelError> {
+        match s {
+            "Inf" => return Ok(Self::INFINITY),
+            "Bottom" => return Ok(Self::BOTTOM),
+            _ => {}
+        }
+        if let Some((numerator, denominator)) = s.split_once('/').or_else(|| s.split_once('⁄')) {
+            let numerator = Self::from_decimal_str(numerator)?;
+            let denominator = Self::from_decimal_str(denominator)?;
+            return Ok(numerator.div(denominator));
+        }
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s),
+        };
+        match vulgar_fraction(unsigned) {
+            Some((numerator, denominator)) => {
+                let value = FractionWheel(T::from(numerator), T::from(denominator)).normalize();
+                Ok(if negative { value.neg() } else { value })
+            }
+            None => Self::from_decimal_str(s),
+        }
+    }
+}
+
+/// The value of a single Unicode vulgar fraction character.
+fn vulgar_fraction(s: &str) -> Option<(i8, i8)> {
+    let value = match s {
+        "½" => (1, 2),
+        "⅓" => (1, 3),
+        "⅔" => (2, 3),
+        "¼" => (1, 4),
+        "¾" => (3, 4),
+        "⅕" => (1, 5),
+        "⅖" => (2, 5),
+        "⅗" => (3, 5),
+        "⅘" => (4, 5),
+        "⅙" => (1, 6),
+        "⅚" => (5, 6),
+        "⅐" => (1, 7),
+        "⅛" => (1, 8),
+        "⅜" => (3, 8),
+        "⅝" => (5, 8),
+        "⅞" => (7, 8),
+        "⅑" => (1, 9),
+        "⅒" => (1, 10),
+        "↉" => (0, 3),
+        _ => return None,
+    };
+    Some(value)
+}
+
+
+// Arithmetic operators
+
+// Add
+
+impl<T: Ring> Add for FractionWheel<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::add(&self, other)
+    }
+}
+
+impl<T: Ring> Add<&FractionWheel<T>> for FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn add(self, other: &Self) -> Self {
+        Self::add(&self, *other)
+    }
+}
+
+impl<T: Ring> Add<FractionWheel<T>> for &FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn add(self, other: FractionWheel<T>) -> FractionWheel<T> {
+        FractionWheel::add(self, other)
+    }
+}
+
+impl<T: Ring> Add<&FractionWheel<T>> for &FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn add(self, other: &FractionWheel<T>) -> FractionWheel<T> {
+        FractionWheel::add(self, *other)
+    }
+}
+
+// Sub
+
+impl<T: Ring> Sub for FractionWheel<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::sub(&self, other)
+    }
+}
+
+impl<T: Ring> Sub<&FractionWheel<T>> for FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn sub(self, other: &Self) -> Self {
+        Self::sub(&self, *other)
+    }
+}
+
+impl<T: Ring> Sub<FractionWheel<T>> for &FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn sub(self, other: FractionWheel<T>) -> FractionWheel<T> {
+        FractionWheel::sub(self, other)
+    }
+}
+
+impl<T: Ring> Sub<&FractionWheel<T>> for &FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn sub(self, other: &FractionWheel<T>) -> FractionWheel<T> {
+        FractionWheel::sub(self, *other)
+    }
+}
+
+// Mul
+
+impl<T: Ring> Mul for FractionWheel<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::mul(&self, other)
+    }
+}
+
+impl<T: Ring> Mul<&FractionWheel<T>> for FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn mul(self, other: &Self) -> Self {
+        Self::mul(&self, *other)
+    }
+}
+
+impl<T: Ring> Mul<FractionWheel<T>> for &FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn mul(self, other: FractionWheel<T>) -> FractionWheel<T> {
+        FractionWheel::mul(self, other)
+    }
+}
+
+impl<T: Ring> Mul<&FractionWheel<T>> for &FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn mul(self, other: &FractionWheel<T>) -> FractionWheel<T> {
+        FractionWheel::mul(self, *other)
+    }
+}
+
+// Div
+
+impl<T: Ring> Div for FractionWheel<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::div(&self, other)
+    }
+}
+
+impl<T: Ring> Div<&FractionWheel<T>> for FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn div(self, other: &Self) -> Self {
+        Self::div(&self, *other)
+    }
+}
+
+impl<T: Ring> Div<FractionWheel<T>> for &FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn div(self, other: FractionWheel<T>) -> FractionWheel<T> {
+        FractionWheel::div(self, other)
+    }
+}
+
+impl<T: Ring> Div<&FractionWheel<T>> for &FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn div(self, other: &FractionWheel<T>) -> FractionWheel<T> {
+        FractionWheel::div(self, *other)
+    }
+}
+
+// Neg
+
+impl<T: Ring> Neg for FractionWheel<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::neg(&self)
+    }
+}
+
+impl<T: Ring> Neg for &FractionWheel<T> {
+    type Output = FractionWheel<T>;
+
+    fn neg(self) -> FractionWheel<T> {
+        FractionWheel::neg(self)
+    }
+}
+
+
+// Iterator reductions
+
+impl<T: Ring> Sum for FractionWheel<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| FractionWheel::add(&acc, x))
+    }
+}
+
+impl<'a, T: Ring> Sum<&'a FractionWheel<T>> for FractionWheel<T> {
+    fn sum<I: Iterator<Item = &'a FractionWheel<T>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T: Ring> Product for FractionWheel<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| FractionWheel::mul(&acc, x))
+    }
+}
+
+impl<'a, T: Ring> Product<&'a FractionWheel<T>> for FractionWheel<T> {
+    fn product<I: Iterator<Item = &'a FractionWheel<T>>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+
+// Comparison operators
+
+impl<T: Ring> PartialEq for FractionWheel<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq(*other)
+    }
+}
+
+impl<T: Ring> Eq for FractionWheel<T> {}
+
+/// Finite values are ordered as rationals.
+/// `INFINITY` and `BOTTOM` are only comparable with themselves.
+impl<T: Ring> PartialOrd for FractionWheel<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.1 == T::ZERO || other.1 == T::ZERO {
+            return if self == other { Some(Ordering::Equal) } else { None };
+        }
+        T::cmp_pairs((self.0, self.1), (other.0, other.1))
+    }
+}
+
+pub type FractionWheel8 = FractionWheel<i8>;
+pub type FractionWheel16 = FractionWheel<i16>;
+pub type FractionWheel32 = FractionWheel<i32>;
+pub type FractionWheel64 = FractionWheel<i64>;
+pub type FractionWheel128 = FractionWheel<i128>;
+
+pub use FractionWheel8 as qw8;
+pub use FractionWheel16 as qw16;
+pub use FractionWheel32 as qw32;
+pub use FractionWheel64 as qw64;
+pub use FractionWheel128 as qw128;
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    type MyWheel = FractionWheel<i32>;
+
+    const ZERO: MyWheel = MyWheel::ZERO;
+    const ONE: MyWheel = MyWheel::ONE;
+    const INFINITY: MyWheel = MyWheel::INFINITY;
+    const BOTTOM: MyWheel = MyWheel::BOTTOM;
+
+    #[inline]
+    fn negative_one() -> MyWheel {
+        -ONE
+    }
+
+    #[inline]
+    fn three() -> MyWheel {
+        ONE + ONE + ONE
+    }
+
+    #[inline]
+    fn negative_two() -> MyWheel {
+        -ONE - ONE
+    }
+
+    #[inline]
+    fn three_halves() -> MyWheel {
+        MyWheel::new(3, 2)
+    }
+
+    #[inline]
+    fn negative_two_fifths() -> MyWheel {
+        MyWheel::new(-2, 5)
+    }
+
+    #[inline]
+    fn any_numbers() -> [MyWheel; 9] {
+        let [zero, one, infinity, bottom] = MyWheel::specials();
+        [
+            zero, one, infinity, bottom,
+            negative_one(), three(), negative_two(),
+            three_halves(), negative_two_fifths()
+        ]
+    }
+
+    #[test]
+    fn inv_is_involution() {
+        for &x in any_numbers().iter() {
+            assert_eq!(x.inv().inv(), x, "x = {:?}", x);
+        }
+    }
+
+    #[test]
+    fn inv_is_multicative() {
+        for &x in any_numbers().iter() {
+            for &y in any_numbers().iter() {
+                assert_eq!((x * y).inv(), y.inv() * x.inv(), "x = {:?}, y = {:?}", x, y);
+            }
+        }
+    }
+
+    /// `(x + y) * z + 0 * z = x * z + y * z`
+    #[test]
+    fn add_is_distributive() {
+        for &x in any_numbers().iter() {
+            for &y in any_numbers().iter() {
+                for &z in any_numbers().iter() {
+                    assert_eq!((x + y) * z + ZERO * z, x * z + y * z, "x = {:?}, y = {:?}, z = {:?}", x, y, z);
+                }
+            }
+        }
+    }
+
+    /// `(x + y * z) / y = x / y + z + 0 * y`
+    #[test]
+    fn add_is_distributive_div() {
+        for &x in any_numbers().iter() {
+            for &y in any_numbers().iter() {
+                for &z in any_numbers().iter() {
+                    assert_eq!((x + y * z) / y, x / y + z + ZERO * y, "x = {:?}, y = {:?}, z = {:?}", x, y, z);
+                }
+            }
+        }
+    }
+
+    /// `0 * 0 = 0`
+    #[test]
+    fn zero_times_zero() {
+        assert_eq!(ZERO * ZERO, ZERO);
+    }
+
+    /// `(x + 0 * y) * z = x * z + 0 * y`
+    #[test]
+    fn zero_times_y() {
+        for &x in any_numbers().iter() {
+            for &y in any_numbers().iter() {
+                for &z in any_numbers().iter() {
+                    assert_eq!((x + ZERO * y) * z, x * z + ZERO * y, "x = {:?}, y = {:?}, z = {:?}", x, y, z);
+                }
+            }
+        }
+    }
+
+    /// `inv(x + 0 * y) = inv(x) + 0 * y`
+    #[test]
+    fn zero_times_y_inv() {
+        for &x in any_numbers().iter() {
+            for &y in any_numbers().iter() {
+                assert_eq!((x + ZERO * y).inv(), x.inv() + ZERO * y, "x = {:?}, y = {:?}", x, y);
+            }
+        }
+    }
+
+    /// `0 / 0 + x = 0 / 0`
+    #[test]
+    fn bottom_addition() {
+        for &x in any_numbers().iter() {
+            assert_eq!(BOTTOM + x, BOTTOM, "x = {:?}", x);
+        }
+    }
+
+    /// `0 * x + 0 * y = 0 * x * y`
+    #[test]
+    fn zero_times_x_plus_zero_times_y() {
+        for &x in any_numbers().iter() {
+            for &y in any_numbers().iter() {
+                assert_eq!(ZERO * x + ZERO * y, ZERO * x * y, "x = {:?}, y = {:?}", x, y);
+            }
+        }
+    }
+
+    /// `x / x = 1 + 0 * x / x`
+    #[test]
+    fn x_div_x() {
+        for &x in any_numbers().iter() {
+            assert_eq!(x / x, ONE + ZERO * x / x, "x = {:?}", x);
+        }
+    }
+
+    /// `x - x = 0 * x * x`
+    #[test]
+    fn x_minus_x() {
+        for &x in any_numbers().iter() {
+            assert_eq!(x - x, ZERO * x * x, "x = {:?}", x);
+        }
+    }
+
+    #[test]
+    fn add_promote_widens() {
+        let x = MyWheel::new(i32::MAX, 1);
+        let y = MyWheel::new(i32::MAX, 1);
+        let sum = x.add_promote(&y);
+        assert_eq!(sum, FractionWheel64::new(2 * i32::MAX as i64, 1));
+    }
+
+    #[test]
+    fn mul_promote_widens() {
+        let x = MyWheel::new(i32::MAX, 3);
+        let y = MyWheel::new(i32::MAX - 1, 5);
+        let product = x.mul_promote(&y);
+        assert_eq!(product, FractionWheel64::new(i32::MAX as i64 * (i32::MAX as i64 - 1), 15));
+    }
+
+    #[test]
+    fn compute_wide() {
+        let add = |x: &MyWheel, y: &MyWheel| *x + *y;
+        let add_wide = |x: &FractionWheel64, y: &FractionWheel64| *x + *y;
+        let x = MyWheel::new(1, 2);
+        assert_eq!(MyWheel::compute_wide(&x, &x, add, add_wide), Either::Left(ONE));
+        let big = MyWheel::new(i32::MAX, 1);
+        assert_eq!(
+            MyWheel::compute_wide(&big, &big, add, add_wide),
+            Either::Right(FractionWheel64::new(2 * i32::MAX as i64, 1)),
+        );
+        let y = MyWheel::new(i32::MAX - 1, 5);
+        assert_eq!(
+            MyWheel::compute_wide(&big, &y, |x, y| *x * *y, |x, y| *x * *y),
+            Either::Right(FractionWheel64::new(i32::MAX as i64 * (i32::MAX as i64 - 1), 5)),
+        );
+        assert_eq!(MyWheel::compute_wide(&BOTTOM, &x, add, add_wide), Either::Left(BOTTOM));
+        assert_eq!(
+            MyWheel::compute_wide(&ZERO, &INFINITY, |x, y| *x * *y, |x, y| *x * *y),
+            Either::Right(FractionWheel64::BOTTOM),
+        );
+    }
+
+    #[test]
+    fn mul_is_exact_or_bottom_i8() {
+        let denominators = [1, 2, 3, 5, 7, 15, 64, 127];
+        let values = || (i8::MIN..=i8::MAX).flat_map(|n| denominators.iter().map(move |&d| FractionWheel8::new(n, d)));
+        for x in values() {
+            for y in values() {
+                let exact = x.widen() * y.widen();
+                let expected = match (i8::try_from(exact.0), i8::try_from(exact.1)) {
+                    (Ok(n), Ok(d)) => FractionWheel(n, d),
+                    _ => FractionWheel8::BOTTOM,
+                };
+                assert_eq!(x * y, expected, "{:?} * {:?}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn promote_keeps_specials() {
+        for &x in any_numbers().iter() {
+            for &y in any_numbers().iter() {
+                assert_eq!(x.add_promote(&y), (x + y).widen());
+                assert_eq!(x.sub_promote(&y), (x - y).widen());
+                assert_eq!(x.mul_promote(&y), (x * y).widen());
+                assert_eq!(x.div_promote(&y), (x / y).widen());
+            }
+        }
+    }
+
+    #[test]
+    fn constructor_methods() {
+        let makers: [fn() -> MyWheel; 4] = [
+            <MyWheel as Wheel>::zero,
+            <MyWheel as Wheel>::one,
+            <MyWheel as Wheel>::infinity,
+            <MyWheel as Wheel>::bottom,
+        ];
+        assert_eq!(makers.map(|make| make()), [ZERO, ONE, INFINITY, BOTTOM]);
+    }
+
+    #[test]
+    fn simplest_between() {
+        let simplest = |lo: MyWheel, hi: MyWheel| MyWheel::simplest_between(&lo, &hi);
+        assert_eq!(simplest(MyWheel::new(1, 3), MyWheel::new(1, 2)), MyWheel::new(2, 5));
+        assert_eq!(simplest(ZERO, ONE), MyWheel::new(1, 2));
+        assert_eq!(simplest(MyWheel::new(3, 10), MyWheel::new(4, 10)), MyWheel::new(1, 3));
+        assert_eq!(simplest(ONE, three()), MyWheel::new(2, 1));
+        assert_eq!(simplest(MyWheel::new(-1, 2), MyWheel::new(1, 2)), ZERO);
+        assert_eq!(simplest(MyWheel::new(-1, 2), MyWheel::new(-1, 3)), MyWheel::new(-2, 5));
+        assert_eq!(simplest(three_halves(), INFINITY), MyWheel::new(2, 1));
+        assert_eq!(simplest(INFINITY, negative_two()), MyWheel::new(-3, 1));
+        assert_eq!(simplest(INFINITY, INFINITY), ZERO);
+    }
+
+    #[test]
+    fn simplest_between_empty() {
+        assert_eq!(MyWheel::simplest_between(&ONE, &ONE), BOTTOM);
+        assert_eq!(MyWheel::simplest_between(&three(), &ONE), BOTTOM);
+        assert_eq!(MyWheel::simplest_between(&BOTTOM, &ONE), BOTTOM);
+        assert_eq!(MyWheel::simplest_between(&ZERO, &BOTTOM), BOTTOM);
+    }
+
+    #[test]
+    fn simplest_between_large_components() {
+        let lo = MyWheel::new(i32::MAX - 2, i32::MAX);
+        let hi = MyWheel::new(i32::MAX - 1, i32::MAX);
+        assert_eq!(MyWheel::simplest_between(&lo, &hi), MyWheel::new((1 << 30) - 1, 1 << 30));
+        assert_eq!(MyWheel::simplest_between(&-hi, &-lo), MyWheel::new(1 - (1 << 30), 1 << 30));
+        // Farey neighbors: the answer is their mediant, which overflows.
+        let lo = MyWheel::new(i32::MAX - 1, i32::MAX);
+        let hi = MyWheel::new(i32::MAX - 2, i32::MAX - 1);
+        assert_eq!(MyWheel::simplest_between(&hi, &lo), BOTTOM);
+        assert_eq!(MyWheel::simplest_between(&INFINITY, &MyWheel::new(i32::MIN, 1)), BOTTOM);
+        assert_eq!(MyWheel::simplest_between(&MyWheel::new(i32::MAX, 1), &INFINITY), BOTTOM);
+        assert_eq!(MyWheel::simplest_between(&INFINITY, &MyWheel::new(i32::MIN, 3)), MyWheel::new(-715_827_883, 1));
+        assert_eq!(MyWheel::simplest_between(&MyWheel::new(i32::MIN, 1), &MyWheel::new(i32::MIN + 1, 1)), BOTTOM);
+        // Agrees with the same search in a wider type whenever the answer fits.
+        let samples = [
+            MyWheel::new(i32::MIN, 1), MyWheel::new(i32::MIN, 3), MyWheel::new(i32::MIN + 1, i32::MAX),
+            MyWheel::new(-7, 3), MyWheel::new(-1, i32::MAX), ZERO, MyWheel::new(1, i32::MAX), MyWheel::new(2, 7),
+            MyWheel::new(i32::MAX - 1, i32::MAX), ONE, MyWheel::new(i32::MAX, 2), MyWheel::new(i32::MAX, 1), INFINITY,
+        ];
+        for lo in samples {
+            for hi in samples {
+                let wide = FractionWheel64::simplest_between(&lo.widen(), &hi.widen());
+                let narrow = match (i32::try_from(wide.0), i32::try_from(wide.1)) {
+                    (Ok(n), Ok(d)) => FractionWheel(n, d),
+                    _ => BOTTOM,
+                };
+                assert_eq!(MyWheel::simplest_between(&lo, &hi), narrow, "{:?} {:?}", lo, hi);
+            }
+        }
+    }
+
+    #[test]
+    fn lerp() {
+        let a = ONE;
+        let b = three();
+        assert_eq!(a.lerp(&b, &ZERO), a);
+        assert_eq!(a.lerp(&b, &ONE), b);
+        assert_eq!(a.lerp(&b, &MyWheel::new(1, 2)), MyWheel::new(2, 1));
+        assert_eq!(a.lerp(&INFINITY, &MyWheel::new(1, 2)), INFINITY);
+        assert_eq!(a.lerp(&INFINITY, &ZERO), BOTTOM);
+    }
+
+    #[test]
+    fn specials_classify() {
+        let expected = [WheelClass::Zero, WheelClass::Normal, WheelClass::Infinity, WheelClass::Bottom];
+        for (x, class) in MyWheel::specials().iter().zip(expected.iter()) {
+            assert_eq!(x.classify(), *class);
+        }
+        assert_eq!(negative_two().classify(), WheelClass::Normal);
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let values = [ONE, three_halves(), negative_two_fifths()];
+        let sum: MyWheel = values.iter().sum();
+        assert_eq!(sum, MyWheel::new(21, 10));
+        let product: MyWheel = values.iter().product();
+        assert_eq!(product, MyWheel::new(-3, 5));
+        assert_eq!(values.into_iter().sum::<MyWheel>(), MyWheel::new(21, 10));
+        assert_eq!(values.into_iter().product::<MyWheel>(), MyWheel::new(-3, 5));
+
+        let empty: [MyWheel; 0] = [];
+        assert_eq!(empty.iter().sum::<MyWheel>(), ZERO);
+        assert_eq!(empty.iter().product::<MyWheel>(), ONE);
+        assert_eq!([ONE, BOTTOM].iter().sum::<MyWheel>(), BOTTOM);
+    }
+
+    #[test]
+    fn eq_reduced() {
+        assert_eq!(MyWheel::new(6, 4), MyWheel::new(3, 2));
+        assert_eq!(MyWheel::new(-6, -4), MyWheel::new(3, 2));
+        assert_eq!(MyWheel::new(5, 0), MyWheel::new(-3, 0));
+        assert_eq!(MyWheel::new(0, -7), ZERO);
+        assert_ne!(MyWheel::new(3, 2), MyWheel::new(-3, 2));
+
+        let large = MyWheel::new(i32::MAX, i32::MAX - 1);
+        assert_eq!(large, MyWheel::new(i32::MAX, i32::MAX - 1));
+        assert_ne!(large, MyWheel::new(i32::MAX - 1, i32::MAX - 2));
+    }
+
+    #[test]
+    fn pow_matches_generic() {
+        for &x in any_numbers().iter() {
+            for exp in -4..=4 {
+                assert_eq!(x.pow(exp), Wheel::pow(&x, exp), "{:?}^{}", x, exp);
+            }
+        }
+        assert_eq!(three_halves().pow(3), MyWheel::new(27, 8));
+        assert_eq!(negative_two_fifths().pow(-3), MyWheel::new(-125, 8));
+        assert_eq!(ZERO.pow(-1), INFINITY);
+        assert_eq!(BOTTOM.pow(0), ONE);
+    }
+
+    #[test]
+    fn pow_near_overflow() {
+        assert_eq!(FractionWheel16::new(3, 2).pow(9), FractionWheel16::new(19683, 512));
+        assert_eq!(FractionWheel16::new(-2, 3).pow(-9), FractionWheel16::new(-19683, 512));
+    }
+
+    #[test]
+    fn pow_overflow_is_bottom() {
+        assert_eq!(FractionWheel16::new(3, 2).pow(10), FractionWheel16::BOTTOM);
+        assert_eq!(FractionWheel16::new(2, 3).pow(-10), FractionWheel16::BOTTOM);
+        assert_eq!(MyWheel::new(3, 2).pow(25), BOTTOM);
+        assert_eq!(MyWheel::new(i32::MIN, 1).pow(1), MyWheel::new(i32::MIN, 1));
+        assert_eq!(MyWheel::new(i32::MIN, 1).pow(-1), BOTTOM);
+        assert_eq!(INFINITY.pow(40), INFINITY);
+    }
+
+    #[test]
+    fn from_decimal_str() {
+        assert_eq!(FractionWheel64::from_decimal_str("3.14"), Ok(FractionWheel64::new(157, 50)));
+        assert_eq!(FractionWheel64::from_decimal_str("-0.5"), Ok(FractionWheel64::new(-1, 2)));
+        assert_eq!(FractionWheel64::from_decimal_str("+42"), Ok(FractionWheel64::new(42, 1)));
+        assert_eq!(FractionWheel64::from_decimal_str("7."), Ok(FractionWheel64::new(7, 1)));
+        assert_eq!(FractionWheel64::from_decimal_str(".25"), Ok(FractionWheel64::new(1, 4)));
+        assert_eq!(FractionWheel64::from_decimal_str("-0"), Ok(FractionWheel64::ZERO));
+        assert_eq!(FractionWheel8::from_decimal_str("0.50000000000"), Ok(FractionWheel8::new(1, 2)));
+        assert_eq!(FractionWheel8::from_decimal_str("-12.7"), Ok(FractionWheel8::new(-127, 10)));
+    }
+
+    #[test]
+    fn from_repeating_decimal() {
+        assert_eq!(FractionWheel64::from_repeating_decimal("0.(3)"), Ok(FractionWheel64::new(1, 3)));
+        assert_eq!(FractionWheel64::from_repeating_decimal("0.1(6)"), Ok(FractionWheel64::new(1, 6)));
+        assert_eq!(FractionWheel64::from_repeating_decimal("-0.(142857)"), Ok(FractionWheel64::new(-1, 7)));
+        assert_eq!(FractionWheel64::from_repeating_decimal("1.(9)"), Ok(FractionWheel64::new(2, 1)));
+        assert_eq!(FractionWheel64::from_repeating_decimal(".(09)"), Ok(FractionWheel64::new(1, 11)));
+        assert_eq!(FractionWheel64::from_repeating_decimal("2.5"), Ok(FractionWheel64::new(5, 2)));
+        for s in ["0.()", "0.(3", "(3)", "0.(3)4", "0.(a)", "-"] {
+            assert!(FractionWheel64::from_repeating_decimal(s).is_err(), "{:?}", s);
+        }
+        assert_eq!(FractionWheel8::from_repeating_decimal("0.(123)"), Err(WheelError::Overflow));
+    }
+
+    #[test]
+    fn from_decimal_str_errors() {
+        for s in ["", "-", ".", "1.2.3", "abc", "1e5", " 1", "--1"] {
+            assert_eq!(FractionWheel64::from_decimal_str(s), Err(WheelError::InvalidFormat), "{:?}", s);
+        }
+        assert_eq!(FractionWheel8::from_decimal_str("128"), Err(WheelError::Overflow));
+        assert_eq!(FractionWheel8::from_decimal_str("0.001"), Err(WheelError::Overflow));
+    }
+
+    #[test]
+    fn reduce_in_place() {
+        let mut x = UnreducedFraction::new(6, -4);
+        x.reduce_in_place();
+        assert_eq!(x, UnreducedFraction::new(-3, 2));
+        assert_eq!(UnreducedFraction::new(6, 4).reduce(), MyWheel::new(3, 2));
+        assert_eq!(MyWheel::from(UnreducedFraction::new(3, -2)).partial_cmp(&ONE), Some(Ordering::Less));
+
+        let mut values = [
+            UnreducedFraction::new(10, 5),
+            UnreducedFraction::new(0, -3),
+            UnreducedFraction::new(-7, 0),
+            UnreducedFraction::new(0, 0),
+        ];
+        // Build unreduced, then reduce once.
+        values[0].numerator *= 3;
+        UnreducedFraction::reduce_slice(&mut values);
+        assert_eq!(values.map(|x| x.reduce()), [MyWheel::new(6, 1), ZERO, INFINITY, BOTTOM]);
+        assert_eq!(values[1], UnreducedFraction::new(0, 1));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn fmt_mixed() {
+        use alloc::string::ToString;
+        assert_eq!(three_halves().fmt_mixed().to_string(), "1 1/2");
+        assert_eq!(MyWheel::new(-3, 2).fmt_mixed().to_string(), "-1 1/2");
+        assert_eq!(MyWheel::new(4, 2).fmt_mixed().to_string(), "2");
+        assert_eq!(MyWheel::new(-1, 2).fmt_mixed().to_string(), "-1/2");
+        assert_eq!(MyWheel::new(-22, 7).fmt_mixed().to_string(), "-3 1/7");
+        assert_eq!(ZERO.fmt_mixed().to_string(), "0");
+        assert_eq!(INFINITY.fmt_mixed().to_string(), "Inf");
+        assert_eq!(BOTTOM.fmt_mixed().to_string(), "Bottom");
+    }
+
+    #[test]
+    fn combinators() {
+        for &x in any_numbers().iter() {
+            assert_eq!(x.neg_inv(), -(ONE / x));
+            assert_eq!(x.one_minus(), ONE - x);
+            assert_eq!(x.inv_one_minus(), ONE / (ONE - x));
+        }
+        assert_eq!(ZERO.neg_inv(), INFINITY);
+        assert_eq!(INFINITY.neg_inv(), ZERO);
+        assert_eq!(BOTTOM.neg_inv(), BOTTOM);
+        assert_eq!(INFINITY.one_minus(), INFINITY);
+        assert_eq!(ONE.inv_one_minus(), INFINITY);
+        assert_eq!(INFINITY.inv_one_minus(), ZERO);
+        assert_eq!(BOTTOM.inv_one_minus(), BOTTOM);
+        assert_eq!(three_halves().inv_one_minus().inv_one_minus().inv_one_minus(), three_halves());
+    }
+
+    #[test]
+    fn to_integer() {
+        assert_eq!(MyWheel::new(4, 2).to_integer(), Some(2));
+        assert_eq!(MyWheel::new(-6, 3).to_integer(), Some(-2));
+        assert_eq!(MyWheel::new(3, 2).to_integer(), None);
+        assert_eq!(ZERO.to_integer(), Some(0));
+        assert_eq!(INFINITY.to_integer(), None);
+        assert_eq!(BOTTOM.to_integer(), None);
+        assert!(three().is_integer());
+        assert!(!negative_two_fifths().is_integer());
+        assert!(!INFINITY.is_integer());
+        assert!(!BOTTOM.is_integer());
+    }
+
+    #[test]
+    fn is_dyadic() {
+        assert!(MyWheel::new(3, 8).is_dyadic());
+        assert!(MyWheel::new(-6, 4).is_dyadic());
+        assert!(three().is_dyadic());
+        assert!(ZERO.is_dyadic());
+        assert!(FractionWheel8::new(1, 64).is_dyadic());
+        assert!(!MyWheel::new(1, 3).is_dyadic());
+        assert!(!MyWheel::new(1, 12).is_dyadic());
+        assert!(!INFINITY.is_dyadic());
+        assert!(!BOTTOM.is_dyadic());
+    }
+
+    #[test]
+    fn partial_ord() {
+        assert!(negative_two() < negative_two_fifths());
+        assert!(negative_two_fifths() < ZERO);
+        assert!(three_halves() < three());
+        assert!(MyWheel::new(2, 4) <= MyWheel::new(1, 2));
+        assert_eq!(INFINITY.partial_cmp(&INFINITY), Some(Ordering::Equal));
+        assert_eq!(BOTTOM.partial_cmp(&BOTTOM), Some(Ordering::Equal));
+        assert_eq!(ONE.partial_cmp(&INFINITY), None);
+        assert_eq!(BOTTOM.partial_cmp(&ZERO), None);
+        for &x in any_numbers().iter() {
+            for &y in any_numbers().iter() {
+                assert_eq!(x == y, x.partial_cmp(&y) == Some(Ordering::Equal));
+            }
+        }
+    }
+
+    #[test]
+    fn partial_ord_large_components() {
+        let x = MyWheel::new(i32::MAX, 2);
+        let y = MyWheel::new(i32::MAX, 3);
+        assert_eq!(x.partial_cmp(&y), Some(Ordering::Greater));
+        assert_eq!(y.partial_cmp(&x), Some(Ordering::Less));
+        assert_eq!((-x).partial_cmp(&-y), Some(Ordering::Less));
+        let x = MyWheel::new(i32::MAX - 1, i32::MAX);
+        let y = MyWheel::new(i32::MAX - 2, i32::MAX - 1);
+        assert_eq!(x.partial_cmp(&y), Some(Ordering::Greater));
+        assert_eq!(MyWheel::new(i32::MIN, 3).partial_cmp(&MyWheel::new(i32::MIN + 1, 3)), Some(Ordering::Less));
+        assert_eq!(x.partial_cmp(&x), Some(Ordering::Equal));
+        let mut values = [MyWheel::new(i32::MAX, 2), MyWheel::new(i32::MAX, 3), MyWheel::new(i32::MAX, 5)];
+        assert_eq!(MyWheel::median(&mut values), MyWheel::new(i32::MAX, 3));
+        let big = qw128::new(i128::MAX, i128::MAX - 1);
+        assert!(big < qw128::new(i128::MAX - 1, i128::MAX - 2));
+        assert!(qw128::new(i128::MAX - 1, i128::MAX) < qw128::ONE);
+        assert!(qw128::new(i128::MIN + 1, 7) < qw128::new(i128::MIN + 2, 7));
+        // Agrees with the exact comparison in a wider type.
+        let samples = [i32::MAX, i32::MAX - 1, i32::MAX / 2, 3, -7, i32::MIN + 1, i32::MIN];
+        for &a in &samples {
+            for &b in &samples[..4] {
+                for &c in &samples {
+                    for &d in &samples[..4] {
+                        let x = MyWheel::new(a, b);
+                        let y = MyWheel::new(c, d);
+                        assert_eq!(x.partial_cmp(&y), x.widen().partial_cmp(&y.widen()), "{:?} {:?}", x, y);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn limit_denominator() {
+        let pi = FractionWheel64::new(3141592653, 1000000000);
+        assert_eq!(pi.limit_denominator(200), FractionWheel64::new(355, 113));
+        assert_eq!(pi.limit_denominator(10), FractionWheel64::new(22, 7));
+        assert_eq!(pi.limit_denominator(1), FractionWheel64::new(3, 1));
+        assert_eq!(pi.limit_denominator(0), FractionWheel64::new(3, 1));
+        assert_eq!((-pi).limit_denominator(200), FractionWheel64::new(-355, 113));
+        assert_eq!(three_halves().limit_denominator(100), three_halves());
+        assert_eq!(INFINITY.limit_denominator(1), INFINITY);
+        assert_eq!(BOTTOM.limit_denominator(1), BOTTOM);
+    }
+
+    #[test]
+    fn limit_denominator_large_components() {
+        let x = MyWheel::new(1_000_000_007, 1_000_000_009);
+        assert_eq!(x.limit_denominator(1000), ONE);
+        assert!(x.roughly_eq_den(&ONE, 1000));
+        let x = MyWheel::new(i32::MAX - 1, i32::MAX);
+        assert_eq!(x.limit_denominator(i32::MAX - 1), MyWheel::new(i32::MAX - 2, i32::MAX - 1));
+        let x = MyWheel::new(i32::MIN, i32::MAX);
+        assert_eq!(x.limit_denominator(1000), MyWheel::new(-1, 1));
+        let pi = MyWheel::new(i32::MAX, 683_565_275);
+        assert_eq!(pi.limit_denominator(200), MyWheel::new(355, 113));
+        assert_eq!((-pi).limit_denominator(200), MyWheel::new(-355, 113));
+        // Agrees with the same search in a wider type.
+        for &(n, d) in &[(i32::MAX, 3), (i32::MAX - 7, 1_234_567), (-1_999_999_999, 2_147_483_629), (1, i32::MAX)] {
+            let x = MyWheel::new(n, d);
+            for max in [1, 2, 7, 100, 65_536, 1_000_000] {
+                assert_eq!(x.limit_denominator(max).widen(), x.widen().limit_denominator(max as i64), "{:?} {}", x, max);
+            }
+        }
+        // No fraction with a small enough denominator is strictly closer.
+        for &(n, d) in &[(i16::MAX, 1_001), (-32_000, 32_767), (i16::MIN, 32_749), (355, 113)] {
+            let x = FractionWheel16::new(n, d);
+            for max in [1i16, 3, 10, 50, 112] {
+                let best = x.limit_denominator(max);
+                let distance = |p: i64, q: i64| ((p * d as i64 - n as i64 * q).abs(), q * d as i64);
+                let (e, f) = distance(best.0 as i64, best.1 as i64);
+                for q in 1..=max as i64 {
+                    let p = (n as i64 * q).div_euclid(d as i64);
+                    for p in [p, p + 1] {
+                        let (g, h) = distance(p, q);
+                        assert!(e * h <= g * f, "{:?} {} {}/{}", x, max, p, q);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn roughly_eq_den() {
+        let a = FractionWheel64::new(3141592653, 1000000000);
+        let b = FractionWheel64::new(245850922, 78256779);
+        assert_ne!(a, b);
+        assert!(a.roughly_eq_den(&b, 200));
+        assert!(a.roughly_eq_den(&FractionWheel64::new(355, 113), 200));
+        assert!(!a.roughly_eq_den(&b, 100000));
+        assert!(!a.roughly_eq_den(&FractionWheel64::INFINITY, 200));
+    }
+
+    #[test]
+    fn abs_sub() {
+        assert_eq!(negative_two_fifths().abs(), MyWheel::new(2, 5));
+        assert_eq!(three_halves().abs(), three_halves());
+        assert_eq!(ONE.abs_sub(&three()), MyWheel::new(2, 1));
+        assert_eq!(negative_two_fifths().abs_sub(&three_halves()), MyWheel::new(19, 10));
+        assert_eq!(three_halves().abs_sub(&three_halves()), ZERO);
+        assert_eq!(ONE.abs_sub(&INFINITY), INFINITY);
+        assert_eq!(INFINITY.abs_sub(&INFINITY), BOTTOM);
+        assert_eq!(BOTTOM.abs_sub(&ONE), BOTTOM);
+        assert_eq!(FractionWheel8::from(i8::MIN).abs(), FractionWheel8::BOTTOM);
+    }
+
+    #[test]
+    fn combine_with_determinant() {
+        type Q = FractionWheel64;
+        // | 1/2  2/3 |
+        // | 3/4  5/6 |
+        let (p, q, r, t) = (Q::new(1, 2), Q::new(2, 3), Q::new(3, 4), Q::new(5, 6));
+        let product = |x: &Q, y: &Q| x.combine_with(y, |a, b, c, d| (a * c, b * d));
+        let difference = |x: &Q, y: &Q| x.combine_with(y, |a, b, c, d| (a * d - b * c, b * d));
+        let det = difference(&product(&p, &t), &product(&q, &r));
+        assert_eq!(det, Q::new(-1, 12));
+        assert_eq!(det, p * t - q * r);
+        assert_eq!(p.combine_with(&q, |_, _, _, _| (4, -6)), Q::new(-2, 3));
+    }
 
-    fn sub(self, other: FractionWheel<T>) -> FractionWheel<T> {
-        FractionWheel::sub(self, other)
+    #[test]
+    fn harmonic_mean() {
+        let values = [ONE, MyWheel::new(1, 2), MyWheel::new(1, 3)];
+        assert_eq!(MyWheel::harmonic_mean(&values), MyWheel::new(1, 2));
+        assert_eq!(MyWheel::harmonic_mean(&[three(), INFINITY]), MyWheel::new(6, 1));
+        assert_eq!(MyWheel::harmonic_mean(&[three(), ZERO]), ZERO);
+        assert_eq!(MyWheel::harmonic_mean(&[three(), BOTTOM]), BOTTOM);
+        assert_eq!(MyWheel::harmonic_mean(&[]), BOTTOM);
     }
-}
 
-impl<T: Ring> Sub<&FractionWheel<T>> for &FractionWheel<T> {
-    type Output = FractionWheel<T>;
+    #[test]
+    fn try_geometric_mean() {
+        assert_eq!(MyWheel::try_geometric_mean(&[MyWheel::new(1, 2), MyWheel::new(2, 9)]), Some(MyWheel::new(1, 3)));
+        assert_eq!(MyWheel::try_geometric_mean(&[MyWheel::new(-8, 1), ONE, MyWheel::new(1, 27)]), Some(MyWheel::new(-2, 3)));
+        assert_eq!(MyWheel::try_geometric_mean(&[ONE, MyWheel::new(2, 1)]), None);
+        assert_eq!(MyWheel::try_geometric_mean(&[negative_one(), ONE]), None);
+        assert_eq!(MyWheel::try_geometric_mean(&[three(), INFINITY]), Some(INFINITY));
+        assert_eq!(MyWheel::try_geometric_mean(&[three(), ZERO]), Some(ZERO));
+        assert_eq!(MyWheel::try_geometric_mean(&[ZERO, INFINITY]), Some(BOTTOM));
+        assert_eq!(MyWheel::try_geometric_mean(&[three(), BOTTOM]), Some(BOTTOM));
+        assert_eq!(MyWheel::try_geometric_mean(&[MyWheel::new(4, 1), MyWheel::new(9, 1)]), Some(MyWheel::new(6, 1)));
+    }
 
-    fn sub(self, other: &FractionWheel<T>) -> FractionWheel<T> {
-        FractionWheel::sub(self, *other)
+    #[test]
+    fn from_unsigned() {
+        assert_eq!(FractionWheel64::from(4_000_000_000u32), FractionWheel64::new(4_000_000_000, 1));
+        assert_eq!(FractionWheel16::from(u8::MAX), FractionWheel16::new(255, 1));
+        assert_eq!(FractionWheel32::from(u16::MAX), FractionWheel32::new(65535, 1));
+        assert_eq!(FractionWheel128::from(u64::MAX), FractionWheel128::new(u64::MAX as i128, 1));
+        assert_eq!(FractionWheel64::from(0u32), FractionWheel64::ZERO);
     }
-}
 
-// Mul
+    #[test]
+    fn component_bits() {
+        assert_eq!(three_halves().component_bits(), (2, 2));
+        assert_eq!(negative_two_fifths().component_bits(), (2, 3));
+        assert_eq!(MyWheel::new(255, 256).component_bits(), (8, 9));
+        assert_eq!(MyWheel::new(i32::MAX, 1).needed_bits(), 31);
+        assert_eq!(FractionWheel8::from(i8::MIN).component_bits(), (8, 1));
+        assert_eq!(ZERO.component_bits(), (0, 1));
+        assert_eq!(INFINITY.component_bits(), (1, 0));
+        assert_eq!(BOTTOM.component_bits(), (0, 0));
+    }
 
-impl<T: Ring> Mul for FractionWheel<T> {
-    type Output = Self;
+    /// `a / b` over all combinations of categories, with `n` a normal value.
+    #[test]
+    fn div_category_matrix() {
+        let n = three_halves();
+        let values = [ZERO, n, INFINITY, BOTTOM];
+        let expected = [
+            [BOTTOM, ZERO, ZERO, BOTTOM],
+            [INFINITY, ONE, ZERO, BOTTOM],
+            [INFINITY, INFINITY, BOTTOM, BOTTOM],
+            [BOTTOM, BOTTOM, BOTTOM, BOTTOM],
+        ];
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                assert_eq!(a / b, expected[i][j], "{:?} / {:?}", a, b);
+                assert_eq!(Wheel::div(a, b), a.mul(b.inv()), "{:?} / {:?}", a, b);
+            }
+        }
+    }
 
-    fn mul(self, other: Self) -> Self {
-        Self::mul(&self, other)
+    #[test]
+    fn checked_pow() {
+        let x = FractionWheel16::new(3, 2);
+        assert_eq!(x.checked_pow(9), Some(FractionWheel16::new(19683, 512)));
+        assert_eq!(x.checked_pow(10), None);
+        assert_eq!(x.checked_pow(0), Some(FractionWheel16::ONE));
+        assert_eq!(x.inv().checked_pow(3), Some(FractionWheel16::new(8, 27)));
+        assert_eq!(FractionWheel16::new(-3, 2).checked_pow(3), Some(FractionWheel16::new(-27, 8)));
+        assert_eq!(FractionWheel16::INFINITY.checked_pow(5), Some(FractionWheel16::INFINITY));
+        assert_eq!(FractionWheel16::BOTTOM.checked_pow(5), Some(FractionWheel16::BOTTOM));
     }
-}
 
-impl<T: Ring> Mul<&FractionWheel<T>> for FractionWheel<T> {
-    type Output = FractionWheel<T>;
+    #[test]
+    fn sign() {
+        let expected = [
+            Sign::Zero, Sign::Positive, Sign::Infinite, Sign::Bottom,
+            Sign::Negative, Sign::Positive, Sign::Negative, Sign::Positive, Sign::Negative,
+        ];
+        assert_eq!(any_numbers().map(|x| x.sign()), expected);
+        assert_eq!(MyWheel::new(3, -4).sign(), Sign::Negative);
+        assert_eq!(MyWheel::new(-3, 0).sign(), Sign::Infinite);
+    }
 
-    fn mul(self, other: &Self) -> Self {
-        Self::mul(&self, *other)
+    #[test]
+    fn checked_sum() {
+        let values = [FractionWheel16::new(1, 251), FractionWheel16::new(1, 241), FractionWheel16::new(-1, 241)];
+        assert_eq!(FractionWheel16::checked_sum(&values), None);
+        assert_eq!(values.iter().sum::<FractionWheel16>(), FractionWheel16::BOTTOM);
+        assert_eq!(FractionWheel16::checked_sum(&values[1..]), Some(FractionWheel16::ZERO));
+
+        let values = [FractionWheel16::new(1, 2), FractionWheel16::new(1, 3), FractionWheel16::new(1, 6)];
+        assert_eq!(FractionWheel16::checked_sum(&values), Some(FractionWheel16::ONE));
+        assert_eq!(FractionWheel16::checked_sum(&[]), Some(FractionWheel16::ZERO));
+        assert_eq!(FractionWheel16::checked_sum(&[FractionWheel16::ONE, FractionWheel16::BOTTOM]), Some(FractionWheel16::BOTTOM));
+        assert_eq!(FractionWheel16::checked_sum(&[FractionWheel16::INFINITY, FractionWheel16::INFINITY]), Some(FractionWheel16::BOTTOM));
     }
-}
 
-impl<T: Ring> Mul<FractionWheel<T>> for &FractionWheel<T> {
-    type Output = FractionWheel<T>;
+    #[test]
+    fn is_invertible() {
+        for x in any_numbers() {
+            assert_eq!(x.is_invertible(), x.classify() == WheelClass::Normal, "x = {:?}", x);
+            if x.is_invertible() {
+                assert_eq!(x * x.inv(), ONE, "x = {:?}", x);
+            } else {
+                assert_ne!(x * x.inv(), ONE, "x = {:?}", x);
+            }
+        }
+    }
 
-    fn mul(self, other: FractionWheel<T>) -> FractionWheel<T> {
-        FractionWheel::mul(self, other)
+    #[test]
+    fn into_sign_magnitude() {
+        let x = MyWheel::new(-3, 2);
+        assert_eq!(x.into_sign_magnitude(), (Sign::Negative, three_halves()));
+        let (sign, magnitude) = x.into_sign_magnitude();
+        assert_eq!(if sign == Sign::Negative { -magnitude } else { magnitude }, x);
+        assert_eq!(three_halves().into_sign_magnitude(), (Sign::Positive, three_halves()));
+        assert_eq!(ZERO.into_sign_magnitude(), (Sign::Zero, ZERO));
+        assert_eq!(INFINITY.into_sign_magnitude(), (Sign::Infinite, INFINITY));
+        assert_eq!(BOTTOM.into_sign_magnitude(), (Sign::Bottom, BOTTOM));
     }
-}
 
-impl<T: Ring> Mul<&FractionWheel<T>> for &FractionWheel<T> {
-    type Output = FractionWheel<T>;
+    #[test]
+    fn from_str() {
+        assert_eq!("3/4".parse::<FractionWheel32>(), Ok(FractionWheel32::new(3, 4)));
+        assert_eq!("-6/4".parse::<FractionWheel32>(), Ok(FractionWheel32::new(-3, 2)));
+        assert_eq!("2.5".parse::<FractionWheel32>(), Ok(FractionWheel32::new(5, 2)));
+        assert_eq!("1/0".parse::<FractionWheel32>(), Ok(FractionWheel32::INFINITY));
+        assert_eq!("0/0".parse::<FractionWheel32>(), Ok(FractionWheel32::BOTTOM));
+        assert_eq!("Inf".parse::<FractionWheel32>(), Ok(FractionWheel32::INFINITY));
+        assert_eq!("Bottom".parse::<FractionWheel32>(), Ok(FractionWheel32::BOTTOM));
+        assert_eq!("1/x".parse::<FractionWheel32>(), Err(WheelError::InvalidFormat));
+        assert_eq!("".parse::<FractionWheel32>(), Err(WheelError::InvalidFormat));
+    }
 
-    fn mul(self, other: &FractionWheel<T>) -> FractionWheel<T> {
-        FractionWheel::mul(self, *other)
+    #[test]
+    fn from_str_unicode() {
+        assert_eq!("½".parse::<FractionWheel32>(), Ok(FractionWheel32::new(1, 2)));
+        assert_eq!("-¾".parse::<FractionWheel32>(), Ok(FractionWheel32::new(-3, 4)));
+        assert_eq!("↉".parse::<FractionWheel32>(), Ok(FractionWheel32::ZERO));
+        assert_eq!("1⁄3".parse::<FractionWheel32>(), Ok(FractionWheel32::new(1, 3)));
+        assert_eq!("½½".parse::<FractionWheel32>(), Err(WheelError::InvalidFormat));
     }
-}
 
-// Div
+    #[test]
+    fn from_parts_reduced() {
+        assert_eq!(MyWheel::from_parts_reduced(3, 2), three_halves());
+        assert_eq!(MyWheel::from_parts_reduced(-2, 5), negative_two_fifths());
+        assert_eq!(MyWheel::from_parts_reduced(0, 1), ZERO);
+        assert_eq!(MyWheel::from_parts_reduced(1, 0), INFINITY);
+        assert_eq!(MyWheel::from_parts_reduced(0, 0), BOTTOM);
+        assert_eq!(MyWheel::from_parts_reduced(i32::MIN, 1), MyWheel::new(i32::MIN, 1));
+    }
 
-impl<T: Ring> Div for FractionWheel<T> {
-    type Output = Self;
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn from_parts_reduced_not_in_lowest_terms() {
+        MyWheel::from_parts_reduced(6, 4);
+    }
 
-    fn div(self, other: Self) -> Self {
-        Self::div(&self, other)
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn from_parts_reduced_negative_denominator() {
+        MyWheel::from_parts_reduced(3, -2);
     }
-}
 
-impl<T: Ring> Div<&FractionWheel<T>> for FractionWheel<T> {
-    type Output = FractionWheel<T>;
+    #[test]
+    fn partial_min_max() {
+        let (a, b) = (negative_two(), three_halves());
+        assert_eq!(a.partial_min(&b), Some(a));
+        assert_eq!(b.partial_min(&a), Some(a));
+        assert_eq!(a.partial_max(&b), Some(b));
+        assert_eq!(ZERO.partial_max(&a), Some(ZERO));
+        assert_eq!(INFINITY.partial_min(&INFINITY), Some(INFINITY));
+        assert_eq!(INFINITY.partial_max(&a), None);
+        for x in any_numbers() {
+            assert_eq!(x.partial_min(&BOTTOM), None, "x = {:?}", x);
+            assert_eq!(BOTTOM.partial_max(&x), None, "x = {:?}", x);
+        }
+    }
 
-    fn div(self, other: &Self) -> Self {
-        Self::div(&self, *other)
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_string_radix() {
+        assert_eq!(MyWheel::new(255, 16).to_string_radix(16), "ff/10");
+        assert_eq!(MyWheel::new(-5, 3).to_string_radix(2), "-101/11");
+        assert_eq!(MyWheel::new(35, 1).to_string_radix(36), "z/1");
+        assert_eq!(ZERO.to_string_radix(10), "0/1");
+        assert_eq!(FractionWheel8::new(i8::MIN, 1).to_string_radix(16), "-80/1");
+        assert_eq!(INFINITY.to_string_radix(16), "Inf");
+        assert_eq!(BOTTOM.to_string_radix(16), "Bottom");
     }
-}
 
-impl<T: Ring> Div<FractionWheel<T>> for &FractionWheel<T> {
-    type Output = FractionWheel<T>;
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "alloc")]
+    fn to_string_radix_rejects_radix() {
+        ONE.to_string_radix(37);
+    }
 
-    fn div(self, other: FractionWheel<T>) -> FractionWheel<T> {
-        FractionWheel::div(self, other)
+    #[test]
+    fn common_denominator() {
+        let values = [MyWheel::new(1, 2), MyWheel::new(1, 3), MyWheel::new(1, 6)];
+        assert_eq!(MyWheel::common_denominator(&values), 6);
+        assert_eq!(MyWheel::common_denominator(&[three(), INFINITY, BOTTOM]), 1);
+        assert_eq!(MyWheel::common_denominator(&[]), 1);
+
+        let values = [FractionWheel16::new(1, 251), FractionWheel16::new(1, 241)];
+        assert_eq!(FractionWheel16::checked_common_denominator(&values), None);
+        assert_eq!(FractionWheel16::common_denominator(&values), 0);
     }
-}
 
-impl<T: Ring> Div<&FractionWheel<T>> for &FractionWheel<T> {
-    type Output = FractionWheel<T>;
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_common_denominator() {
+        let values = [MyWheel::new(1, 2), MyWheel::new(-1, 3), MyWheel::new(1, 6), INFINITY, BOTTOM];
+        let den = MyWheel::common_denominator(&values);
+        assert_eq!(MyWheel::to_common_denominator(&values, den), [(3, 6), (-2, 6), (1, 6), (1, 0), (0, 0)]);
+        assert_eq!(MyWheel::to_common_denominator(&values[..2], 4), [(2, 4), (0, 0)]);
+    }
 
-    fn div(self, other: &FractionWheel<T>) -> FractionWheel<T> {
-        FractionWheel::div(self, *other)
+    #[test]
+    fn expand_to() {
+        assert_eq!(MyWheel::new(1, 3).expand_to(12), Some((4, 12)));
+        assert_eq!(MyWheel::new(-5, 2).expand_to(2), Some((-5, 2)));
+        assert_eq!(MyWheel::new(3, 1).expand_to(-4), Some((-12, -4)));
+        assert_eq!(ZERO.expand_to(7), Some((0, 7)));
+        assert_eq!(MyWheel::new(1, 3).expand_to(10), None);
+        assert_eq!(MyWheel::new(1, 3).expand_to(0), None);
+        assert_eq!(FractionWheel8::new(100, 3).expand_to(6), None);
+        assert_eq!(INFINITY.expand_to(12), None);
+        assert_eq!(BOTTOM.expand_to(12), None);
     }
-}
 
-// Neg
+    #[test]
+    fn neg_specials() {
+        assert_eq!(-ZERO, ZERO);
+        assert_eq!(-INFINITY, INFINITY);
+        assert_eq!(-BOTTOM, BOTTOM);
+        assert_eq!(-&INFINITY, INFINITY);
+        assert_eq!(Wheel::neg(&INFINITY), INFINITY);
+        // The representation stays canonical, not just equal.
+        let neg_infinity = -INFINITY;
+        assert_eq!((neg_infinity.0, neg_infinity.1), (1, 0));
+        let neg_zero = -ZERO;
+        assert_eq!((neg_zero.0, neg_zero.1), (0, 1));
+        let neg_bottom = -BOTTOM;
+        assert_eq!((neg_bottom.0, neg_bottom.1), (0, 0));
+        assert_eq!(-FractionWheel8::INFINITY, FractionWheel8::INFINITY);
+        assert_eq!(-FractionWheel128::INFINITY, FractionWheel128::INFINITY);
+        assert_eq!(-FractionWheel128::BOTTOM, FractionWheel128::BOTTOM);
+    }
 
-impl<T: Ring> Neg for FractionWheel<T> {
-    type Output = Self;
+    #[test]
+    fn to_f64_round() {
+        assert_eq!(MyWheel::new(1, 3).to_f64_round(), 1.0 / 3.0);
+        assert_eq!(MyWheel::new(-7, 2).to_f64_round(), -3.5);
+        assert_eq!(MyWheel::new(i32::MAX, 1).to_f64_round(), i32::MAX as f64);
+        assert_eq!(ZERO.to_f64_round(), 0.0);
+        assert_eq!(INFINITY.to_f64_round(), f64::INFINITY);
+        assert!(BOTTOM.to_f64_round().is_nan());
+
+        // Converting the components first rounds twice and misses these by one ulp.
+        let hard = [
+            (3133162727462620917, 828752135068405976, 3.780578770037204),
+            (6235435337493575641, 581226978119338534, 10.728055600016049),
+            (1723498190144867753, 5436695199189107919, 0.3170121051483501),
+            (-6421352091455045521, 1768833157765780807, -3.6302757347481416),
+        ];
+        for (numerator, denominator, expected) in hard {
+            let x = FractionWheel64::new(numerator, denominator);
+            assert_eq!(x.to_f64_round(), expected, "x = {:?}", x);
+            assert_ne!(numerator as f64 / denominator as f64, expected, "x = {:?}", x);
+        }
+        let x = FractionWheel128::new(36371287747045362522517213269393567939, 520531);
+        assert_eq!(x.to_f64_round(), 6.987343260448535e31);
+        let x = FractionWheel128::new(1, i128::MAX);
+        assert_eq!(x.to_f64_round(), 2f64.powi(-127));
+        assert_eq!(FractionWheel128::new(i128::MIN, 1).to_f64_round(), -(2f64.powi(127)));
+    }
 
-    fn neg(self) -> Self {
-        Self::neg(&self)
+    #[test]
+    fn div_floor_ceil() {
+        let seven_halves = MyWheel::new(7, 2);
+        assert_eq!(seven_halves.div_floor(&ONE), Some(3));
+        assert_eq!(seven_halves.div_ceil(&ONE), Some(4));
+        assert_eq!(seven_halves.div_floor(&negative_one()), Some(-4));
+        assert_eq!(seven_halves.div_ceil(&negative_one()), Some(-3));
+        assert_eq!(seven_halves.div_floor(&MyWheel::new(7, 4)), Some(2));
+        assert_eq!(seven_halves.div_ceil(&MyWheel::new(7, 4)), Some(2));
+        assert_eq!(MyWheel::new(-1, 3).div_floor(&ONE), Some(-1));
+        assert_eq!(MyWheel::new(-1, 3).div_ceil(&ONE), Some(0));
+        assert_eq!(ZERO.div_floor(&three()), Some(0));
+        assert_eq!(seven_halves.div_floor(&ZERO), None);
+        assert_eq!(ZERO.div_ceil(&ZERO), None);
+        assert_eq!(INFINITY.div_floor(&three()), None);
+        assert_eq!(seven_halves.div_floor(&INFINITY), Some(0));
+        assert_eq!(FractionWheel8::new(i8::MIN, 1).div_floor(&FractionWheel8::new(3, 1)), Some(-43));
     }
-}
 
-impl<T: Ring> Neg for &FractionWheel<T> {
-    type Output = FractionWheel<T>;
+    #[test]
+    fn is_farey_neighbor() {
+        assert!(MyWheel::new(1, 2).is_farey_neighbor(&MyWheel::new(2, 3)));
+        assert!(MyWheel::new(2, 3).is_farey_neighbor(&MyWheel::new(1, 2)));
+        assert!(!MyWheel::new(1, 2).is_farey_neighbor(&MyWheel::new(3, 4)));
+        assert!(!MyWheel::new(1, 2).is_farey_neighbor(&MyWheel::new(1, 2)));
+        assert!(ZERO.is_farey_neighbor(&MyWheel::new(1, 7)));
+        assert!(three().is_farey_neighbor(&MyWheel::new(4, 1)));
+        assert!(MyWheel::new(-1, 2).is_farey_neighbor(&MyWheel::new(-1, 3)));
+        assert!(MyWheel::new(-1, 2).is_farey_neighbor(&MyWheel::new(-2, 3)));
+        assert!(!MyWheel::new(-1, 2).is_farey_neighbor(&MyWheel::new(1, 2)));
+        assert!(!INFINITY.is_farey_neighbor(&ONE));
+        assert!(!ONE.is_farey_neighbor(&BOTTOM));
+
+        // The cross products overflow `i32` here.
+        let max = i32::MAX;
+        assert!(MyWheel::new(max, max - 1).is_farey_neighbor(&MyWheel::new(max - 1, max - 2)));
+        let fib = MyWheel::new(1836311903, 1134903170);
+        assert!(fib.is_farey_neighbor(&MyWheel::new(1134903170, 701408733)));
+        assert!(!fib.is_farey_neighbor(&MyWheel::new(1134903171, 701408733)));
+        assert!(!fib.is_farey_neighbor(&ONE));
+    }
 
-    fn neg(self) -> FractionWheel<T> {
-        FractionWheel::neg(self)
+    #[test]
+    fn numerator_over() {
+        assert_eq!(MyWheel::new(1, 3).numerator_over(6), Some(2));
+        assert_eq!(MyWheel::new(1, 3).numerator_over(100), Some(33));
+        assert_eq!(MyWheel::new(2, 3).numerator_over(100), Some(67));
+        assert_eq!(MyWheel::new(-2, 3).numerator_over(100), Some(-67));
+        assert_eq!(MyWheel::new(1, 2).numerator_over(1), Some(1));
+        assert_eq!(MyWheel::new(-1, 2).numerator_over(1), Some(-1));
+        assert_eq!(MyWheel::new(1, 4).numerator_over(2), Some(1));
+        assert_eq!(three().numerator_over(0), Some(0));
+        assert_eq!(ZERO.numerator_over(7), Some(0));
+        assert_eq!(INFINITY.numerator_over(7), None);
+        assert_eq!(BOTTOM.numerator_over(7), None);
+        assert_eq!(MyWheel::new(i32::MAX, 2).numerator_over(4), None);
+        assert_eq!(MyWheel::new(i32::MAX, 3).numerator_over(3), Some(i32::MAX));
     }
-}
 
+    #[test]
+    fn convergents() {
+        let pi: [FractionWheel32; 5] = [
+            FractionWheel32::new(3, 1), FractionWheel32::new(22, 7), FractionWheel32::new(333, 106),
+            FractionWheel32::new(355, 113), FractionWheel32::new(103993, 33102),
+        ];
+        let mut convergents = FractionWheel32::convergents(core::f64::consts::PI);
+        for expected in pi {
+            assert_eq!(convergents.next(), Some(expected));
+        }
+        assert!(FractionWheel32::convergents(core::f64::consts::PI).count() < 20);
+        assert_eq!(FractionWheel8::convergents(core::f64::consts::PI).last(), Some(FractionWheel8::new(22, 7)));
 
-// Comparison operators
+        let mut convergents = FractionWheel32::convergents(-1.75);
+        assert_eq!(convergents.next(), Some(FractionWheel32::new(-2, 1)));
+        assert_eq!(convergents.next(), Some(FractionWheel32::new(-7, 4)));
+        assert_eq!(convergents.next(), None);
 
-impl<T: Ring> PartialEq for FractionWheel<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.eq(*other)
+        assert_eq!(FractionWheel32::convergents(f64::NAN).next(), None);
+        assert_eq!(FractionWheel32::convergents(f64::INFINITY).next(), None);
+        assert_eq!(FractionWheel32::convergents(1e300).next(), None);
     }
-}
 
-impl<T: Ring> Eq for FractionWheel<T> {}
-
-pub type FractionWheel8 = FractionWheel<i8>;
-pub type FractionWheel16 = FractionWheel<i16>;
-pub type FractionWheel32 = FractionWheel<i32>;
-pub type FractionWheel64 = FractionWheel<i64>;
-pub type FractionWheel128 = FractionWheel<i128>;
+    #[test]
+    fn reciprocal_sum() {
+        let ohms = |n| MyWheel::new(n, 1);
+        assert_eq!(MyWheel::reciprocal_sum(&[ohms(2), ohms(2)]), ONE);
+        assert_eq!(MyWheel::reciprocal_sum(&[ohms(3), ohms(6)]), ohms(2));
+        assert_eq!(MyWheel::reciprocal_sum(&[ohms(1), ohms(2), ohms(3)]), MyWheel::new(6, 11));
+        assert_eq!(MyWheel::reciprocal_sum(&[ohms(3), INFINITY, ohms(6)]), ohms(2));
+        assert_eq!(MyWheel::reciprocal_sum(&[ohms(3), ZERO, ohms(6)]), ZERO);
+        assert_eq!(MyWheel::reciprocal_sum(&[ZERO, INFINITY]), ZERO);
+        assert_eq!(MyWheel::reciprocal_sum(&[ZERO, ZERO]), BOTTOM);
+        assert_eq!(MyWheel::reciprocal_sum(&[ohms(3), BOTTOM]), BOTTOM);
+        assert_eq!(MyWheel::reciprocal_sum(&[INFINITY]), INFINITY);
+        assert_eq!(MyWheel::reciprocal_sum(&[]), INFINITY);
+    }
 
-pub use FractionWheel8 as qw8;
-pub use FractionWheel16 as qw16;
-pub use FractionWheel32 as qw32;
-pub use FractionWheel64 as qw64;
-pub use FractionWheel128 as qw128;
+    #[test]
+    fn from_f64() {
+        fn half<W: Wheel>() -> W {
+            W::from_f64(0.5)
+        }
+        assert_eq!(half::<MyWheel>(), MyWheel::new(1, 2));
+        assert_eq!(half::<FractionWheel8>(), FractionWheel8::new(1, 2));
+        assert_eq!(MyWheel::from_f64(0.1), MyWheel::new(1, 10));
+        assert_eq!(FractionWheel64::from_f64(0.1), FractionWheel64::new(1, 10));
+        assert_eq!(MyWheel::from_f64(-2.75), MyWheel::new(-11, 4));
+        assert_eq!(MyWheel::from_f64(-0.0), ZERO);
+        assert_eq!(MyWheel::from_f64(core::f64::consts::PI), MyWheel::new(245850922, 78256779));
+        assert_eq!(FractionWheel8::from_f64(core::f64::consts::PI), FractionWheel8::new(22, 7));
+        assert_eq!(MyWheel::from_f64(f64::NAN), BOTTOM);
+        assert_eq!(MyWheel::from_f64(f64::INFINITY), INFINITY);
+        assert_eq!(MyWheel::from_f64(f64::NEG_INFINITY), INFINITY);
+        assert_eq!(FractionWheel8::from_f64(1000.0), FractionWheel8::BOTTOM);
+    }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn powers() {
+        use alloc::vec::Vec;
+        let powers: Vec<_> = MyWheel::new(3, 2).powers().take(5).collect();
+        assert_eq!(powers, [
+            MyWheel::ONE,
+            MyWheel::new(3, 2),
+            MyWheel::new(9, 4),
+            MyWheel::new(27, 8),
+            MyWheel::new(81, 16),
+        ]);
+        // 3^19 still fits in i32, 3^20 does not.
+        assert_eq!(MyWheel::new(3, 2).powers().count(), 20);
+        assert_eq!(MyWheel::new(3, 2).powers().last(), Some(MyWheel::new(1162261467, 524288)));
+        assert_eq!(MyWheel::new(-1, 2).powers().nth(3), Some(MyWheel::new(-1, 8)));
+        assert_eq!(INFINITY.powers().take(3).last(), Some(INFINITY));
+        assert_eq!(BOTTOM.powers().nth(1), Some(BOTTOM));
+    }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    type MyWheel = FractionWheel<i32>;
+    #[test]
+    fn split_integer_fraction() {
+        assert_eq!(MyWheel::new(7, 2).split_integer_fraction(), (3, MyWheel::new(1, 2)));
+        assert_eq!(MyWheel::new(-7, 2).split_integer_fraction(), (-3, MyWheel::new(-1, 2)));
+        assert_eq!(MyWheel::new(-1, 3).split_integer_fraction(), (0, MyWheel::new(-1, 3)));
+        assert_eq!(MyWheel::new(8, 2).split_integer_fraction(), (4, ZERO));
+        assert_eq!(MyWheel::new(-5, 1).split_integer_fraction(), (-5, ZERO));
+        assert_eq!(ZERO.split_integer_fraction(), (0, ZERO));
+        assert_eq!(INFINITY.split_integer_fraction(), (0, INFINITY));
+        assert_eq!(BOTTOM.split_integer_fraction(), (0, BOTTOM));
+    }
 
-    const ZERO: MyWheel = MyWheel::ZERO;
-    const ONE: MyWheel = MyWheel::ONE;
-    const INFINITY: MyWheel = MyWheel::INFINITY;
-    const BOTTOM: MyWheel = MyWheel::BOTTOM;
+    #[test]
+    fn try_sqrt_cbrt() {
+        assert_eq!(MyWheel::new(4, 9).try_sqrt(), Some(MyWheel::new(2, 3)));
+        assert_eq!(MyWheel::new(8, 18).try_sqrt(), Some(MyWheel::new(2, 3)));
+        assert_eq!(MyWheel::new(2, 1).try_sqrt(), None);
+        assert_eq!(MyWheel::new(1, 2).try_sqrt(), None);
+        assert_eq!(MyWheel::new(-4, 9).try_sqrt(), None);
+        assert_eq!(MyWheel::new(46340 * 46340, 1).try_sqrt(), Some(MyWheel::new(46340, 1)));
+        assert_eq!(ZERO.try_sqrt(), Some(ZERO));
+        assert_eq!(INFINITY.try_sqrt(), Some(INFINITY));
+        assert_eq!(BOTTOM.try_sqrt(), Some(BOTTOM));
+        assert_eq!(MyWheel::new(-8, 27).try_cbrt(), Some(MyWheel::new(-2, 3)));
+        assert_eq!(MyWheel::new(4, 1).try_cbrt(), None);
+        assert_eq!(FractionWheel8::new(i8::MIN, 1).try_cbrt(), None);
+        assert_eq!(INFINITY.try_cbrt(), Some(INFINITY));
+    }
 
-    #[inline]
-    fn negative_one() -> MyWheel {
-        -ONE
+    #[test]
+    fn percent() {
+        assert_eq!(MyWheel::new(1, 3).as_percent(), MyWheel::new(100, 3));
+        assert_eq!(MyWheel::new(-1, 8).as_percent(), MyWheel::new(-25, 2));
+        assert_eq!(MyWheel::new(1, 3).as_per_mille(), MyWheel::new(1000, 3));
+        assert_eq!(MyWheel::new(3, 1000).as_per_mille(), MyWheel::from(3));
+        assert_eq!(MyWheel::new(2, 7).times_scalar_int(14), MyWheel::from(4));
+        assert_eq!(MyWheel::new(2, 7).times_scalar_int(0), ZERO);
+        assert_eq!(FractionWheel8::new(1, 2).as_per_mille(), FractionWheel8::BOTTOM);
+        assert_eq!(FractionWheel8::new(1, 125).as_per_mille(), FractionWheel8::from(8));
+        assert_eq!(INFINITY.as_percent(), INFINITY);
+        assert_eq!(BOTTOM.as_per_mille(), BOTTOM);
+        assert_eq!(INFINITY.times_scalar_int(0), BOTTOM);
     }
 
-    #[inline]
-    fn three() -> MyWheel {
-        ONE + ONE + ONE
+    #[test]
+    fn is_close_to_integer() {
+        assert!(!<MyWheel as Wheel>::is_close_to_integer(&MyWheel::new(3, 2), 1.0));
+        assert!(<MyWheel as Wheel>::is_close_to_integer(&MyWheel::new(6, 2), 0.0));
+        assert!(!<MyWheel as Wheel>::is_close_to_integer(&ZERO, 1.0));
+        assert!(!<MyWheel as Wheel>::is_close_to_integer(&INFINITY, 1.0));
+        assert!(!<MyWheel as Wheel>::is_close_to_integer(&BOTTOM, 1.0));
     }
 
-    #[inline]
-    fn negative_two() -> MyWheel {
-        -ONE - ONE
+    #[test]
+    fn cross_multiply() {
+        let a = FractionWheel16::new(2, 3);
+        let b = FractionWheel16::new(5, 7);
+        assert_eq!(a.cross_multiply(&b), Some((14, 15)));
+        assert_eq!(b.cross_multiply(&a), Some((15, 14)));
+        assert_eq!(FractionWheel16::INFINITY.cross_multiply(&a), Some((3, 0)));
+        let big = FractionWheel16::new(300, 301);
+        assert_eq!(big.cross_multiply(&FractionWheel16::new(1, 100)), Some((30000, 301)));
+        assert_eq!(big.cross_multiply(&FractionWheel16::new(1, 200)), None);
+        assert_eq!(big.cross_multiply(&FractionWheel16::new(200, 1)), None);
     }
 
-    #[inline]
-    fn three_halves() -> MyWheel {
-        MyWheel::new(3, 2)
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_egyptian() {
+        assert_eq!(MyWheel::new(4, 13).to_egyptian().unwrap(), [
+            MyWheel::new(1, 4), MyWheel::new(1, 18), MyWheel::new(1, 468),
+        ]);
+        assert_eq!(MyWheel::new(7, 3).to_egyptian().unwrap(), [MyWheel::from(2), MyWheel::new(1, 3)]);
+        assert_eq!(MyWheel::new(-3, 4).to_egyptian().unwrap(), [MyWheel::new(-1, 2), MyWheel::new(-1, 4)]);
+        assert_eq!(MyWheel::new(1, 7).to_egyptian().unwrap(), [MyWheel::new(1, 7)]);
+        assert_eq!(ZERO.to_egyptian().unwrap(), []);
+        assert_eq!(INFINITY.to_egyptian(), None);
+        assert_eq!(BOTTOM.to_egyptian(), None);
+        for x in [MyWheel::new(3, 7), MyWheel::new(99, 100), MyWheel::new(-17, 5), MyWheel::new(6, 7)] {
+            let terms = x.to_egyptian().unwrap();
+            assert_eq!(terms.iter().sum::<MyWheel>(), x, "{:?}", x);
+            assert!(terms.iter().skip_while(|t| t.1 == 1).all(|t| t.0.abs() == 1));
+        }
+        // The greedy expansion of 5/121 has a 25-digit last denominator.
+        assert_eq!(FractionWheel64::new(5, 121).to_egyptian(), None);
+        // `a·n` itself overflows on the first step.
+        assert_eq!(MyWheel::new(i32::MAX - 1, i32::MAX).to_egyptian(), None);
+        assert_eq!(MyWheel::new(-(i32::MAX - 1), i32::MAX).to_egyptian(), None);
     }
 
-    #[inline]
-    fn negative_two_fifths() -> MyWheel {
-        MyWheel::new(-2, 5)
+    #[test]
+    fn rational_gcd_lcm() {
+        let (a, b) = (MyWheel::new(3, 4), MyWheel::new(1, 6));
+        assert_eq!(a.rational_gcd(&b), MyWheel::new(1, 12));
+        assert_eq!(a.rational_lcm(&b), MyWheel::new(3, 2));
+        assert_eq!(MyWheel::new(-3, 4).rational_gcd(&MyWheel::new(-9, 10)), MyWheel::new(3, 20));
+        assert_eq!(MyWheel::new(-3, 4).rational_lcm(&MyWheel::new(9, 10)), MyWheel::new(9, 2));
+        assert_eq!(MyWheel::from(6).rational_gcd(&MyWheel::from(4)), MyWheel::from(2));
+        assert_eq!(MyWheel::from(6).rational_lcm(&MyWheel::from(4)), MyWheel::from(12));
+        assert_eq!(ZERO.rational_gcd(&MyWheel::new(-2, 3)), MyWheel::new(2, 3));
+        assert_eq!(ZERO.rational_gcd(&ZERO), ZERO);
+        assert_eq!(ZERO.rational_lcm(&a), ZERO);
+        assert_eq!(INFINITY.rational_gcd(&a), BOTTOM);
+        assert_eq!(a.rational_lcm(&BOTTOM), BOTTOM);
+        let min = FractionWheel8::from(i8::MIN);
+        assert_eq!(min.rational_gcd(&min), FractionWheel8::BOTTOM);
+        assert_eq!(FractionWheel8::new(1, 100).rational_gcd(&FractionWheel8::new(1, 99)), FractionWheel8::BOTTOM);
+        assert_eq!(FractionWheel8::from(100).rational_lcm(&FractionWheel8::from(99)), FractionWheel8::BOTTOM);
     }
 
-    #[inline]
-    fn any_numbers() -> [MyWheel; 9] {
-        [
-            ZERO, ONE, INFINITY, BOTTOM,
-            negative_one(), three(), negative_two(),
-            three_halves(), negative_two_fifths()
-        ]
+    #[test]
+    fn median() {
+        let mut values = [MyWheel::new(5, 2), MyWheel::new(-1, 3), MyWheel::from(7)];
+        assert_eq!(MyWheel::median(&mut values), MyWheel::new(5, 2));
+        assert_eq!(values, [MyWheel::new(-1, 3), MyWheel::new(5, 2), MyWheel::from(7)]);
+        let mut values = [MyWheel::from(4), MyWheel::new(1, 2), MyWheel::new(2, 3), ZERO];
+        assert_eq!(MyWheel::median(&mut values), MyWheel::new(7, 12));
+        let mut values = [MyWheel::new(1, 3), MyWheel::new(1, 3)];
+        assert_eq!(MyWheel::median(&mut values), MyWheel::new(1, 3));
+        assert_eq!(MyWheel::median(&mut [ONE, INFINITY, ZERO]), BOTTOM);
+        assert_eq!(MyWheel::median(&mut [ONE, BOTTOM, ZERO]), BOTTOM);
+        assert_eq!(MyWheel::median(&mut []), BOTTOM);
     }
 
     #[test]
-    fn inv_is_involution() {
-        for &x in any_numbers().iter() {
-            println!("{:?} == {:?}", x.inv().inv(), x);
-            assert_eq!(x.inv().inv(), x);
+    fn checked_sub_div() {
+        fn ratio_of_differences(a: MyWheel, b: MyWheel, c: MyWheel) -> Result<MyWheel, WheelError> {
+            a.checked_sub(&b)?.checked_div(&c.checked_sub(&b)?)
         }
+        assert_eq!(ratio_of_differences(ONE, MyWheel::new(1, 2), MyWheel::from(2)), Ok(MyWheel::new(1, 3)));
+        assert_eq!(ratio_of_differences(ONE, ONE, ONE), Err(WheelError::Undefined));
+        assert_eq!(INFINITY.checked_sub(&INFINITY), Err(WheelError::Undefined));
+        assert_eq!(ONE.checked_sub(&INFINITY), Ok(INFINITY));
+        assert_eq!(ONE.checked_div(&ZERO), Ok(INFINITY));
     }
 
     #[test]
-    fn inv_is_multicative() {
-        for &x in any_numbers().iter() {
-            for &y in any_numbers().iter() {
-                println!("{:?} == {:?}", (x * y).inv(), y.inv() * x.inv());
-                assert_eq!((x * y).inv(), y.inv() * x.inv());
-            }
-        }
+    fn from_dyadic() {
+        assert_eq!(MyWheel::from_dyadic(0.375), Some(MyWheel::new(3, 8)));
+        assert_eq!(MyWheel::from_dyadic(-2.5), Some(MyWheel::new(-5, 2)));
+        assert_eq!(MyWheel::from_dyadic(96.0), Some(MyWheel::from(96)));
+        assert_eq!(MyWheel::from_dyadic(-0.0), Some(ZERO));
+        assert_eq!(MyWheel::from_dyadic(2f64.powi(30)), Some(MyWheel::from(1 << 30)));
+        assert_eq!(MyWheel::from_dyadic(2f64.powi(31)), None);
+        assert_eq!(MyWheel::from_dyadic(2f64.powi(-30)), Some(MyWheel::new(1, 1 << 30)));
+        assert_eq!(MyWheel::from_dyadic(2f64.powi(-31)), None);
+        assert_eq!(MyWheel::from_dyadic(0.1), None);
+        assert_eq!(FractionWheel64::from_dyadic(0.1), Some(FractionWheel64::new(3602879701896397, 1 << 55)));
+        assert_eq!(FractionWheel8::from_dyadic(1.0 / 64.0), Some(FractionWheel8::new(1, 64)));
+        assert_eq!(FractionWheel8::from_dyadic(1.0 / 128.0), None);
+        assert_eq!(FractionWheel128::from_dyadic(f64::from_bits(1)), None);
+        assert_eq!(MyWheel::from_dyadic(f64::NAN), None);
+        assert_eq!(MyWheel::from_dyadic(f64::INFINITY), None);
     }
 
-    /// `(x + y) * z + 0 * z = x * z + y * z`
     #[test]
-    fn add_is_distributive() {
-        for &x in any_numbers().iter() {
-            for &y in any_numbers().iter() {
-                for &z in any_numbers().iter() {
-                    println!("{:?} == {:?}", (x + y) * z + ZERO * z, x * z + y * z);
-                    assert_eq!((x + y) * z + ZERO * z, x * z + y * z);
-                }
-            }
-        }
+    fn scale_by_pow2() {
+        let x = MyWheel::new(3, 20);
+        assert_eq!(Wheel::scale_by_pow2(&x, 3), x * MyWheel::from(8));
+        assert_eq!(Wheel::scale_by_pow2(&x, -3), MyWheel::new(3, 160));
+        assert_eq!(Wheel::scale_by_pow2(&FractionWheel8::new(1, 64), 7), FractionWheel8::from(2));
+        assert_eq!(Wheel::scale_by_pow2(&FractionWheel8::new(3, 1), 6), FractionWheel8::BOTTOM);
+        assert_eq!(Wheel::scale_by_pow2(&ONE, i32::MIN), BOTTOM);
+        assert_eq!(Wheel::scale_by_pow2(&ZERO, i32::MAX), ZERO);
+        assert_eq!(Wheel::scale_by_pow2(&INFINITY, -4), INFINITY);
+        assert_eq!(Wheel::scale_by_pow2(&BOTTOM, 4), BOTTOM);
     }
 
-    /// `(x + y * z) / y = x / y + z + 0 * y`
     #[test]
-    fn add_is_distributive_div() {
-        for &x in any_numbers().iter() {
-            for &y in any_numbers().iter() {
-                for &z in any_numbers().iter() {
-                    println!("{:?} == {:?}", (x + y * z) / y, x / y + z + ZERO * y);
-                    assert_eq!((x + y * z) / y, x / y + z + ZERO * y);
-                }
-            }
+    fn approximate_with_error() {
+        use core::f64::consts::PI;
+        let (fraction, error) = MyWheel::approximate_with_error(PI, 10);
+        assert_eq!(fraction, MyWheel::new(22, 7));
+        assert_eq!(error, PI - 22.0 / 7.0);
+        assert!(error < 0.0);
+        let (fraction, error) = MyWheel::approximate_with_error(PI, 1000);
+        assert_eq!(fraction, MyWheel::new(355, 113));
+        assert!(error.abs() < 3e-7);
+        assert_eq!(MyWheel::approximate_with_error(0.75, 100), (MyWheel::new(3, 4), 0.0));
+        // The semiconvergent 179/57 is closer than the convergent 22/7.
+        assert_eq!(MyWheel::approximate_with_error(PI, 57).0, MyWheel::new(179, 57));
+        assert_eq!(MyWheel::approximate_with_error(PI, 56).0, MyWheel::new(22, 7));
+        assert_eq!(MyWheel::approximate_with_error(-0.33, 10).0, MyWheel::new(-1, 3));
+        let (fraction, error) = MyWheel::approximate_with_error(1e30, 10);
+        assert_eq!(fraction, BOTTOM);
+        assert!(error.is_nan());
+        let (fraction, error) = MyWheel::approximate_with_error(f64::NAN, 10);
+        assert_eq!(fraction, BOTTOM);
+        assert!(error.is_nan());
+        let (fraction, error) = MyWheel::approximate_with_error(f64::INFINITY, 10);
+        assert_eq!(fraction, INFINITY);
+        assert!(error.is_nan());
+    }
+
+    #[test]
+    fn is_one() {
+        for x in any_numbers() {
+            assert_eq!(x.is_one(), x == ONE, "x = {:?}", x);
         }
+        for x in <MyWheel as crate::laws::WheelSamples>::samples() {
+            assert_eq!(Wheel::is_one(x), *x == MyWheel::ONE, "x = {:?}", x);
+        }
+        assert!(MyWheel::new(7, 7).is_one());
+        assert!(UnreducedFraction::new(-3, -3).reduce().is_one());
+        assert!(!MyWheel::new(-1, -1).neg().is_one());
     }
+}
+
+
+#[cfg(test)]
+mod i128_boundary_test {
+    use super::*;
+
+    const MIN: i128 = i128::MIN;
+    const MAX: i128 = i128::MAX;
 
-    /// `0 * 0 = 0`
     #[test]
-    fn zero_times_zero() {
-        assert_eq!(ZERO * ZERO, ZERO);
+    fn new_at_min() {
+        assert_eq!(qw128::new(MIN, 1), qw128::from(MIN));
+        assert_eq!(qw128::new(MIN, 2), qw128::from(MIN / 2));
+        assert_eq!(qw128::new(MIN, MIN), qw128::ONE);
+        assert_eq!(qw128::new(MIN, 0), qw128::INFINITY);
+        assert_eq!(qw128::new(0, MIN), qw128::ZERO);
+        assert_eq!(qw128::new(MAX, MIN), qw128::BOTTOM);
+        let half_min = qw128::new(2, MIN);
+        assert_eq!((half_min.0, half_min.1), (-1, 1 << 126));
     }
 
-    /// `(x + 0 * y) * z = x * z + 0 * y`
     #[test]
-    fn zero_times_y() {
-        for &x in any_numbers().iter() {
-            for &y in any_numbers().iter() {
-                for &z in any_numbers().iter() {
-                    println!("{:?} == {:?}", (x + ZERO * y) * z, x * z + ZERO * y);
-                    assert_eq!((x + ZERO * y) * z, x * z + ZERO * y);
-                }
-            }
-        }
+    fn unrepresentable_is_bottom() {
+        // -1 / 2^127 and 2^127 / 1 don't fit.
+        assert_eq!(qw128::new(1, MIN), qw128::BOTTOM);
+        assert_eq!(qw128::new(MIN, -1), qw128::BOTTOM);
+        assert_eq!(-qw128::from(MIN), qw128::BOTTOM);
+        assert_eq!(qw128::from(MAX) + qw128::ONE, qw128::BOTTOM);
+        assert_eq!(qw128::from(MIN) - qw128::ONE, qw128::BOTTOM);
+        assert_eq!(qw128::from(MIN) * qw128::new(-1, 1), qw128::BOTTOM);
+        assert_eq!(qw128::from(MAX) * qw128::from(2i128), qw128::BOTTOM);
     }
 
-    /// `inv(x + 0 * y) = inv(x) + 0 * y`
     #[test]
-    fn zero_times_y_inv() {
-        for &x in any_numbers().iter() {
-            for &y in any_numbers().iter() {
-                println!("{:?} == {:?}", (x + ZERO * y).inv(), x.inv() + ZERO * y);
-                assert_eq!((x + ZERO * y).inv(), x.inv() + ZERO * y);
+    fn add_near_max() {
+        assert_eq!(qw128::from(MAX - 1) + qw128::ONE, qw128::from(MAX));
+        assert_eq!(qw128::from(MIN + 1) - qw128::ONE, qw128::from(MIN));
+        // The denominators' product overflows, but the sum fits.
+        let big = 1i128 << 100;
+        let sum = qw128::new(1, 3 * big) + qw128::new(1, 5 * big);
+        assert_eq!(sum, qw128::new(1, 15 * (big >> 3)));
+        assert_eq!(qw128::new(MAX, 2) - qw128::new(MAX, 2), qw128::ZERO);
+        assert_eq!(qw128::new(1, MAX) + qw128::new(1, MAX), qw128::new(2, MAX));
+    }
+
+    #[test]
+    fn mul_near_max() {
+        assert_eq!(qw128::new(MAX, 3) * qw128::new(3, MAX), qw128::ONE);
+        assert_eq!(qw128::new(MAX, 7) * qw128::new(14, MAX), qw128::from(2i128));
+        assert_eq!(qw128::new(MAX, 7) * qw128::new(14, MAX - 1), qw128::new(MAX, (MAX - 1) / 2));
+        assert_eq!(qw128::from(MIN) * qw128::new(1, 2), qw128::from(MIN / 2));
+        assert_eq!(qw128::new(MIN, 3) * qw128::new(3, 2), qw128::from(MIN / 2));
+        assert_eq!(qw128::new(MIN, 15) * qw128::new(3, 7), qw128::new(MIN, 35));
+        assert_eq!(qw128::new(3, 7) * qw128::new(MIN, 15), qw128::new(MIN, 35));
+        assert_eq!(qw128::new(MIN, 15) / qw128::new(7, 3), qw128::new(MIN, 35));
+        assert_eq!(qw64::new(i64::MIN, 15) * qw64::new(3, 7), qw64::new(i64::MIN, 35));
+        // 1 / MIN does not fit, but division does not need it.
+        assert_eq!(qw128::from(MIN) / qw128::from(MIN), qw128::ONE);
+        assert_eq!(qw128::new(MIN, 3) / qw128::new(MIN, 5), qw128::new(5, 3));
+        assert_eq!(qw128::ONE / qw128::from(MIN), qw128::BOTTOM);
+        assert_eq!(qw128::from(MIN).inv(), qw128::BOTTOM);
+    }
+}
+
+/// Randomized checks of the overflow guarantees of `FractionWheel32`:
+/// small components never overflow, and any result is either exact or `BOTTOM`.
+/// Edge components (`i32::MIN` numerators, denominators next to `i32::MAX`)
+/// are mixed in, since that is where intermediate negation and inversion overflow.
+#[cfg(test)]
+mod overflow_property_test {
+    use super::*;
+    use crate::laws::check_wheel_laws_on;
+
+    /// SplitMix64, so runs are reproducible without extra dependencies.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        }
+
+        /// A nonzero-denominator fraction with components of at most `bits` bits.
+        fn fraction(&mut self, bits: u32) -> qw32 {
+            let component = |rng: &mut Self| (rng.next() >> (64 - bits)) as i32;
+            let numerator = component(self) - component(self);
+            let denominator = component(self).max(1);
+            qw32::new(numerator, denominator)
+        }
+
+        /// Components from every scale up to the full `i32` range,
+        /// with an edge component one time in four.
+        fn any_fraction(&mut self) -> qw32 {
+            const NUMERATORS: [i32; 4] = [i32::MIN, i32::MIN + 1, i32::MAX, i32::MAX - 1];
+            const DENOMINATORS: [i32; 4] = [i32::MAX, i32::MAX - 1, i32::MAX - 2, 1];
+            let bits = (self.next() % 31) as u32 + 1;
+            let x = self.fraction(bits);
+            match self.next() % 8 {
+                0 => qw32::new(NUMERATORS[(self.next() % 4) as usize], x.1),
+                1 => qw32::new(x.0, DENOMINATORS[(self.next() % 4) as usize]),
+                _ => x,
             }
         }
     }
 
-    /// `0 / 0 + x = 0 / 0`
-    #[test]
-    fn bottom_addition() {
-        for &x in any_numbers().iter() {
-            println!("{:?} == {:?}", BOTTOM + x, BOTTOM);
-            assert_eq!(BOTTOM + x, BOTTOM);
+    fn to_i128(x: qw32) -> qw128 {
+        qw128::new(x.0.into(), x.1.into())
+    }
+
+    fn apply<W: Wheel>(op: char, a: &W, b: &W) -> W {
+        match op {
+            '+' => a.add(b),
+            '-' => a.sub(b),
+            '*' => a.mul(b),
+            _ => a.div(b),
         }
     }
 
-    /// `0 * x + 0 * y = 0 * x * y`
+    fn fits_in_i32(x: qw128) -> bool {
+        i32::try_from(x.0).is_ok() && i32::try_from(x.1).is_ok()
+    }
+
     #[test]
-    fn zero_times_x_plus_zero_times_y() {
-        for &x in any_numbers().iter() {
-            for &y in any_numbers().iter() {
-                println!("{:?} == {:?}", ZERO * x + ZERO * y, ZERO * x * y);
-                assert_eq!(ZERO * x + ZERO * y, ZERO * x * y);
-            }
+    fn laws_hold_exactly_for_small_components() {
+        let mut rng = Rng(1);
+        for _ in 0..20 {
+            let mut values = [qw32::ZERO, qw32::ONE, qw32::INFINITY, qw32::BOTTOM].to_vec();
+            values.extend((0..8).map(|_| rng.fraction(6)));
+            check_wheel_laws_on(&values, |a, b| a == b);
         }
     }
 
-    /// `x / x = 1 + 0 * x / x`
     #[test]
-    fn x_div_x() {
-        for &x in any_numbers().iter() {
-            println!("{:?} == {:?}", x / x, ONE + ZERO * x / x);
-            assert_eq!(x / x, ONE + ZERO * x / x);
-        }
+    fn sum_that_fits_only_after_reducing() {
+        // 3/2 + 1017060195/879541582 = 2336372568/879541582 = 1168186284/439770791.
+        let sum = qw32::new(3, 2) + qw32::new(1017060195, 879541582);
+        assert_eq!(sum, qw32::new(1168186284, 439770791));
     }
 
-    /// `x - x = 0 * x * x`
     #[test]
-    fn x_minus_x() {
-        for &x in any_numbers().iter() {
-            println!("{:?} == {:?}", x - x, ZERO * x * x);
-            assert_eq!(x - x, ZERO * x * x);
+    fn results_are_exact_or_bottom() {
+        let mut rng = Rng(2);
+        for _ in 0..20000 {
+            let (a, b) = (rng.any_fraction(), rng.any_fraction());
+            for op in ['+', '-', '*', '/'] {
+                let result = apply(op, &a, &b);
+                let exact = apply(op, &to_i128(a), &to_i128(b));
+                if fits_in_i32(exact) {
+                    assert_eq!(to_i128(result), exact, "{:?} {} {:?}", a, op, b);
+                } else {
+                    assert_eq!(result, qw32::BOTTOM, "{:?} {} {:?}", a, op, b);
+                }
+            }
         }
     }
 }
@@ -0,0 +1,228 @@
+//! Interval arithmetic over wheel numbers.
+
+use crate::Wheel;
+
+use core::ops::{Add, Sub, Mul, Div};
+
+/// A closed interval `[lo, hi]`.
+/// `INFINITY` as a bound means the interval is unbounded on that side,
+/// so `[INFINITY, INFINITY]` is the whole line.
+/// A `BOTTOM` bound makes the interval undefined, and it stays so through arithmetic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval<W: Wheel> {
+    pub lo: W,
+    pub hi: W,
+}
+
+impl<W: Wheel + PartialOrd + Copy> Interval<W> {
+    /// The whole line.
+    pub const ENTIRE: Self = Interval { lo: W::INFINITY, hi: W::INFINITY };
+
+    /// The undefined interval.
+    pub const BOTTOM: Self = Interval { lo: W::BOTTOM, hi: W::BOTTOM };
+
+    pub fn new(lo: W, hi: W) -> Self {
+        Interval { lo, hi }
+    }
+
+    /// The single point `[x, x]`.
+    pub fn point(x: W) -> Self {
+        Interval { lo: x, hi: x }
+    }
+
+    pub fn is_bottom(&self) -> bool {
+        self.lo == W::BOTTOM || self.hi == W::BOTTOM
+    }
+
+    pub fn is_bounded(&self) -> bool {
+        self.lo != W::INFINITY && self.hi != W::INFINITY
+    }
+
+    /// Whether `x` lies in the interval.
+    pub fn contains(&self, x: &W) -> bool {
+        if self.is_bottom() || *x == W::BOTTOM || *x == W::INFINITY {
+            return false;
+        }
+        (self.lo == W::INFINITY || self.lo <= *x) && (self.hi == W::INFINITY || *x <= self.hi)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        if self.is_bottom() || other.is_bottom() {
+            return Self::BOTTOM;
+        }
+        Interval {
+            lo: add_bound(&self.lo, &other.lo),
+            hi: add_bound(&self.hi, &other.hi),
+        }
+    }
+
+    fn neg(&self) -> Self {
+        Interval { lo: self.hi.neg(), hi: self.lo.neg() }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    /// Unbounded factors give `ENTIRE` unless the other factor is exactly zero.
+    /// So does a bound product that overflows to `INFINITY`, while one that
+    /// comes out `BOTTOM` (fraction overflow) gives `BOTTOM`.
+    fn mul(&self, other: &Self) -> Self {
+        if self.is_bottom() || other.is_bottom() {
+            return Self::BOTTOM;
+        }
+        let zero = Self::point(W::ZERO);
+        if *self == zero || *other == zero {
+            return zero;
+        }
+        if !self.is_bounded() || !other.is_bounded() {
+            return Self::ENTIRE;
+        }
+        let products = [
+            self.lo.mul(&other.lo),
+            self.lo.mul(&other.hi),
+            self.hi.mul(&other.lo),
+            self.hi.mul(&other.hi),
+        ];
+        if products.contains(&W::BOTTOM) {
+            return Self::BOTTOM;
+        }
+        if products.contains(&W::INFINITY) {
+            return Self::ENTIRE;
+        }
+        let mut lo = products[0];
+        let mut hi = products[0];
+        for p in products.iter().skip(1) {
+            if *p < lo {
+                lo = *p;
+            }
+            if *p > hi {
+                hi = *p;
+            }
+        }
+        Interval { lo, hi }
+    }
+
+    /// A divisor containing zero widens the result to `ENTIRE`.
+    fn div(&self, other: &Self) -> Self {
+        if self.is_bottom() || other.is_bottom() {
+            return Self::BOTTOM;
+        }
+        if other.contains(&W::ZERO) {
+            return Self::ENTIRE;
+        }
+        // The divisor lies entirely on one side of zero, so its reciprocal
+        // is an ordinary interval; an unbounded end becomes zero.
+        let reciprocal = Interval { lo: other.hi.inv(), hi: other.lo.inv() };
+        self.mul(&reciprocal)
+    }
+}
+
+/// Adds bounds, keeping an unbounded side unbounded.
+fn add_bound<W: Wheel>(a: &W, b: &W) -> W {
+    if *a == W::INFINITY || *b == W::INFINITY {
+        W::INFINITY
+    } else {
+        a.add(b)
+    }
+}
+
+impl<W: Wheel + PartialOrd + Copy> Add for Interval<W> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::add(&self, &other)
+    }
+}
+
+impl<W: Wheel + PartialOrd + Copy> Sub for Interval<W> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::sub(&self, &other)
+    }
+}
+
+impl<W: Wheel + PartialOrd + Copy> Mul for Interval<W> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::mul(&self, &other)
+    }
+}
+
+impl<W: Wheel + PartialOrd + Copy> Div for Interval<W> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::div(&self, &other)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{w64, qw32};
+
+    fn q(n: i32, d: i32) -> qw32 {
+        qw32::new(n, d)
+    }
+
+    fn interval(lo: i32, hi: i32) -> Interval<qw32> {
+        Interval::new(qw32::from(lo), qw32::from(hi))
+    }
+
+    #[test]
+    fn add_sub() {
+        assert_eq!(interval(1, 2) + interval(3, 4), interval(4, 6));
+        assert_eq!(interval(1, 2) - interval(3, 4), interval(-3, -1));
+        let half_line = Interval::new(qw32::ONE, qw32::INFINITY);
+        assert_eq!(half_line + interval(1, 2), Interval::new(q(2, 1), qw32::INFINITY));
+        assert_eq!(half_line - half_line, Interval::ENTIRE);
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!(interval(1, 2) * interval(-1, 3), interval(-2, 6));
+        assert_eq!(interval(-3, -2) * interval(-5, 4), interval(-12, 15));
+        assert_eq!(interval(0, 0) * Interval::ENTIRE, interval(0, 0));
+        assert_eq!(interval(1, 2) * Interval::new(qw32::ONE, qw32::INFINITY), Interval::ENTIRE);
+    }
+
+    #[test]
+    fn mul_overflow() {
+        let a = Interval::new(w64::new(1.0), w64::new(1e200));
+        assert_eq!(a * a, Interval::ENTIRE);
+        let b = Interval::new(w64::new(-1e200), w64::new(1.0));
+        assert_eq!(a * b, Interval::ENTIRE);
+        assert_eq!(interval(1, 100000) * interval(1, 100000), Interval::BOTTOM);
+        assert_eq!(interval(1, 1000) * interval(1, 1000), interval(1, 1000000));
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(interval(1, 2) / interval(-1, 1), Interval::ENTIRE);
+        assert_eq!(interval(1, 2) / interval(0, 1), Interval::ENTIRE);
+        assert_eq!(interval(1, 2) / interval(2, 4), Interval::new(q(1, 4), q(1, 1)));
+        assert_eq!(interval(1, 2) / interval(-4, -2), Interval::new(q(-1, 1), q(-1, 4)));
+        assert_eq!(interval(1, 2) / Interval::new(qw32::new(2, 1), qw32::INFINITY), interval(0, 1));
+
+        let a = Interval::new(w64::new(1.0), w64::new(2.0));
+        let b = Interval::new(w64::new(-1.0), w64::new(1.0));
+        assert_eq!(a / b, Interval::ENTIRE);
+        assert_eq!(a / a, Interval::new(w64::new(0.5), w64::new(2.0)));
+    }
+
+    #[test]
+    fn bottom_propagates() {
+        let bottom = Interval::new(qw32::BOTTOM, qw32::ONE);
+        assert!(bottom.is_bottom());
+        assert_eq!(bottom + interval(1, 2), Interval::BOTTOM);
+        assert_eq!(interval(1, 2) * bottom, Interval::BOTTOM);
+        assert_eq!(interval(1, 2) / bottom, Interval::BOTTOM);
+        assert!(!interval(1, 2).contains(&qw32::BOTTOM));
+        assert!(interval(1, 2).contains(&q(3, 2)));
+        assert!(Interval::ENTIRE.contains(&q(-7, 2)));
+    }
+}
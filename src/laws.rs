@@ -0,0 +1,110 @@
+//! Reusable checks of the wheel axioms, for testing `Wheel` implementations.
+
+use crate::Wheel;
+use crate::float::Wheel64;
+use crate::fraction::FractionWheel;
+
+use core::fmt::Debug;
+
+/// A representative set of values: all four special values and some normals.
+pub trait WheelSamples: Wheel + Clone + 'static {
+    fn samples() -> &'static [Self];
+}
+
+static WHEEL64_SAMPLES: [Wheel64; 9] = [
+    Wheel64::ZERO, Wheel64::ONE, Wheel64::INFINITY, Wheel64::BOTTOM,
+    Wheel64::NEGATIVE_ONE, Wheel64::new(3.0), Wheel64::new(-2.0),
+    Wheel64::new(0.5), Wheel64::new(-0.25),
+];
+
+impl WheelSamples for Wheel64 {
+    fn samples() -> &'static [Self] {
+        &WHEEL64_SAMPLES
+    }
+}
+
+static FRACTION_WHEEL32_SAMPLES: [FractionWheel<i32>; 9] = [
+    FractionWheel::ZERO, FractionWheel::ONE, FractionWheel::INFINITY, FractionWheel::BOTTOM,
+    FractionWheel::new_unnormalized(-1, 1), FractionWheel::new_unnormalized(3, 1),
+    FractionWheel::new_unnormalized(-2, 1), FractionWheel::new_unnormalized(3, 2),
+    FractionWheel::new_unnormalized(-2, 5),
+];
+
+impl WheelSamples for FractionWheel<i32> {
+    fn samples() -> &'static [Self] {
+        &FRACTION_WHEEL32_SAMPLES
+    }
+}
+
+/// Checks the wheel axioms over `W::samples()` using `==`. Panics on a violation.
+pub fn check_wheel_laws<W: WheelSamples + Debug>() {
+    check_wheel_laws_with::<W, _>(|a, b| a == b);
+}
+
+/// Checks the wheel axioms over `W::samples()` using `eq` to compare,
+/// e.g. an approximate equality for floating point wheels. Panics on a violation.
+pub fn check_wheel_laws_with<W: WheelSamples + Debug, E: Fn(&W, &W) -> bool>(eq: E) {
+    check_wheel_laws_on(W::samples(), eq);
+}
+
+/// Checks the wheel axioms over every pair and triple drawn from `values`,
+/// e.g. randomly generated ones, using `eq` to compare. Panics on a violation.
+pub fn check_wheel_laws_on<W: Wheel + Clone + Debug, E: Fn(&W, &W) -> bool>(values: &[W], eq: E) {
+    let zero = W::ZERO;
+    let one = W::ONE;
+    let check = |law: &str, a: W, b: W| {
+        assert!(eq(&a, &b), "{}: {:?} != {:?}", law, a, b);
+    };
+
+    check("0 * 0 = 0", zero.mul(&zero), W::ZERO);
+    for x in values {
+        check("inv(inv(x)) = x", x.inv().inv(), x.clone());
+        check("0/0 + x = 0/0", W::BOTTOM.add(x), W::BOTTOM);
+        check("x / x = 1 + 0 * x / x", x.div(x), one.add(&zero.mul(x).div(x)));
+        check("x - x = 0 * x * x", x.sub(x), zero.mul(x).mul(x));
+        for y in values {
+            check("inv(x * y) = inv(y) * inv(x)", x.mul(y).inv(), y.inv().mul(&x.inv()));
+            check("inv(x + 0 * y) = inv(x) + 0 * y", x.add(&zero.mul(y)).inv(), x.inv().add(&zero.mul(y)));
+            check("0 * x + 0 * y = 0 * x * y", zero.mul(x).add(&zero.mul(y)), zero.mul(x).mul(y));
+            for z in values {
+                check(
+                    "(x + y) * z + 0 * z = x * z + y * z",
+                    x.add(y).mul(z).add(&zero.mul(z)),
+                    x.mul(z).add(&y.mul(z)),
+                );
+                check(
+                    "(x + y * z) / y = x / y + z + 0 * y",
+                    x.add(&y.mul(z)).div(y),
+                    x.div(y).add(z).add(&zero.mul(y)),
+                );
+                check(
+                    "(x + 0 * y) * z = x * z + 0 * y",
+                    x.add(&zero.mul(y)).mul(z),
+                    x.mul(z).add(&zero.mul(y)),
+                );
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fraction_wheel_laws() {
+        check_wheel_laws::<FractionWheel<i32>>();
+    }
+
+    #[test]
+    fn wheel64_laws() {
+        check_wheel_laws_with::<Wheel64, _>(|a, b| a.roughly_eq(*b));
+    }
+
+    #[test]
+    fn samples_cover_categories() {
+        assert_eq!(crate::numeric::class_counts(Wheel64::samples()), [1, 6, 1, 1]);
+        assert_eq!(crate::numeric::class_counts(FractionWheel::<i32>::samples()), [1, 6, 1, 1]);
+    }
+}
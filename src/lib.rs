@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_std)]
+#![no_std]
 //! # Wheel
 //! Wheel algebra library for Rust 
 //! 
@@ -9,8 +9,25 @@
 //! assert_eq!(inf, w64::INFINITY);
 //! ```
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod error;
 pub mod fraction;
 pub mod float;
+pub mod vector;
+pub mod matrix;
+pub mod interval;
+pub mod numeric;
+pub mod laws;
+
+mod math;
+
+use core::cmp::Ordering;
+
+pub use error::WheelError;
 
 pub use fraction::FractionWheel;
 pub use fraction::FractionWheel8;
@@ -28,10 +45,91 @@ pub use float::Wheel32;
 pub use float::Wheel64;
 pub use float::w32;
 pub use float::w64;
+pub use float::ToleranceWheel64;
+pub use float::StrictWheel64;
+
+pub use vector::WheelVec;
+pub use matrix::Mat2;
+pub use interval::Interval;
+
+/// The four categories a wheel value falls into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WheelClass {
+    Zero,
+    Infinity,
+    Bottom,
+    /// Any other value, including `ONE`.
+    Normal,
+}
+
+impl WheelClass {
+    /// A stable numeric code: 0 zero, 1 normal, 2 infinity, 3 bottom (the
+    /// order of `Wheel::specials`). Shared by `numeric::class_counts` and the
+    /// `Wheel64` encodings.
+    pub const fn code(self) -> u8 {
+        match self {
+            WheelClass::Zero => 0,
+            WheelClass::Normal => 1,
+            WheelClass::Infinity => 2,
+            WheelClass::Bottom => 3,
+        }
+    }
+
+    /// Inverse of `code`; `None` for an unknown code.
+    pub const fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(WheelClass::Zero),
+            1 => Some(WheelClass::Normal),
+            2 => Some(WheelClass::Infinity),
+            3 => Some(WheelClass::Bottom),
+            _ => None,
+        }
+    }
+}
+
+/// The object-safe part of `Wheel`, so that values of different wheel types
+/// can be inspected through `&dyn DynWheel` or `Box<dyn DynWheel>`.
+/// `Wheel` itself is not object-safe: its constants and most of its methods
+/// mention `Self`. Every `Wheel` implements this trait.
+pub trait DynWheel {
+    /// The category of this value, as `Wheel::classify`.
+    fn class(&self) -> WheelClass;
+
+    /// Whether this value has a multiplicative inverse, as `Wheel::is_invertible`.
+    fn invertible(&self) -> bool;
+}
+
+impl<W: Wheel> DynWheel for W {
+    fn class(&self) -> WheelClass {
+        self.classify()
+    }
+
+    fn invertible(&self) -> bool {
+        self.is_invertible()
+    }
+}
+
+/// The sign of a wheel value, with the unsigned specials kept apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sign {
+    Negative,
+    Zero,
+    Positive,
+    /// `INFINITY`, which has no sign.
+    Infinite,
+    Bottom,
+}
 
 /// Wheel is an algebraic structure where division is always defined.
 /// Division is not necesarily the same as the multiplicative inverse.
 /// Eq is always defined, but PartialOrd is not.
+///
+/// This trait is not object safe, since values are returned by value.
+/// Generic code should take `W: Wheel` instead of `dyn Wheel`.
+///
+/// The operators (`+`, `&a * &b`, ...) are implemented on the concrete types only.
+/// Generic code does arithmetic on borrowed values through the methods,
+/// e.g. `a.add(b)` for `a, b: &W`, or the `numeric::add_ref` family.
 pub trait Wheel: PartialEq + Eq + Sized {
     /// Additive identity. There is no signed zero.
     const ZERO: Self;
@@ -46,6 +144,91 @@ pub trait Wheel: PartialEq + Eq + Sized {
     /// But it is a number, so it can be used in calculations.
     const BOTTOM: Self;
 
+    /// Returns `ZERO`. Unlike the constant, this can be used as a function pointer.
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    /// Returns `ONE`.
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    /// Returns `INFINITY`.
+    fn infinity() -> Self {
+        Self::INFINITY
+    }
+
+    /// Returns `BOTTOM`.
+    fn bottom() -> Self {
+        Self::BOTTOM
+    }
+
+    /// `[ZERO, ONE, INFINITY, BOTTOM]`, handy for iterating the special values.
+    fn specials() -> [Self; 4] {
+        [Self::ZERO, Self::ONE, Self::INFINITY, Self::BOTTOM]
+    }
+
+    /// The wheel value for a real given as `f64`: wrapped directly by the float
+    /// wheels and reconstructed as a fraction by `FractionWheel`.
+    /// NaN gives `BOTTOM` and either infinity gives `INFINITY`.
+    fn from_f64(value: f64) -> Self;
+
+    /// The category of this value.
+    fn classify(&self) -> WheelClass {
+        if *self == Self::ZERO {
+            WheelClass::Zero
+        } else if *self == Self::INFINITY {
+            WheelClass::Infinity
+        } else if *self == Self::BOTTOM {
+            WheelClass::Bottom
+        } else {
+            WheelClass::Normal
+        }
+    }
+
+    /// Whether `inv` is the multiplicative inverse here, i.e. `self * self.inv()`
+    /// is `ONE` rather than `ONE + 0 * self / self`. True exactly for normal values.
+    /// This goes by category, so a float such as `49` counts even though
+    /// `49 * (1 / 49)` rounds to just below one.
+    fn is_invertible(&self) -> bool {
+        self.classify() == WheelClass::Normal
+    }
+
+    /// Whether `self` is `ONE`. Never true for `INFINITY` or `BOTTOM`.
+    fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+
+    /// The smaller value, or `None` if either is `BOTTOM` or they are unordered
+    /// (`INFINITY` only compares with itself).
+    fn partial_min(&self, other: &Self) -> Option<Self>
+    where
+        Self: PartialOrd + Clone,
+    {
+        if *self == Self::BOTTOM || *other == Self::BOTTOM {
+            return None;
+        }
+        match self.partial_cmp(other)? {
+            Ordering::Greater => Some(other.clone()),
+            _ => Some(self.clone()),
+        }
+    }
+
+    /// The larger value, or `None` as for `partial_min`.
+    fn partial_max(&self, other: &Self) -> Option<Self>
+    where
+        Self: PartialOrd + Clone,
+    {
+        if *self == Self::BOTTOM || *other == Self::BOTTOM {
+            return None;
+        }
+        match self.partial_cmp(other)? {
+            Ordering::Less => Some(other.clone()),
+            _ => Some(self.clone()),
+        }
+    }
+
     /// Normal addition.
     fn add(&self, other: &Self) -> Self;
 
@@ -64,9 +247,107 @@ pub trait Wheel: PartialEq + Eq + Sized {
     /// Always defined. Not the same as the multiplicative inverse.
     fn inv(&self) -> Self;
 
+    /// Whether `self` is an integer, or for the float wheels within `tol` of one.
+    /// `FractionWheel` ignores `tol`. The special categories (`ZERO`, `INFINITY`
+    /// and `BOTTOM`) return `false`.
+    fn is_close_to_integer(&self, tol: f64) -> bool;
+
+    /// An approximate `inv` refined by `iterations` Newton–Raphson steps,
+    /// for targets where division is slow. The specials follow `inv` exactly.
+    /// The default is just `inv`; the float wheels override it with a
+    /// division-free iteration.
+    fn approx_recip(&self, _iterations: u32) -> Self {
+        self.inv()
+    }
+
+    /// Sum of the elementwise products of `a` and `b`, up to the shorter length.
+    /// `Wheel64` overrides this with compensated summation; other wheels
+    /// add the products in order.
+    fn dot(a: &[Self], b: &[Self]) -> Self {
+        a.iter().zip(b).fold(Self::ZERO, |acc, (x, y)| acc.add(&x.mul(y)))
+    }
+
+    /// Like `dot`, but as accurate as the representation allows: `Wheel64`
+    /// keeps the rounding error of every product and every addition and adds
+    /// it back at the end. The default is `dot`, which is already exact for fractions.
+    fn sum_of_products(a: &[Self], b: &[Self]) -> Self {
+        Self::dot(a, b)
+    }
+
     /// Always defined as `self * other.inv()`.
     /// `x / x` is not always one
+    ///
+    /// For the special values this gives (rows `self`, columns `other`, `n` normal):
+    ///
+    /// | `/`        | `ZERO`     | `n`        | `INFINITY` | `BOTTOM` |
+    /// |------------|------------|------------|------------|----------|
+    /// | `ZERO`     | `BOTTOM`   | `ZERO`     | `ZERO`     | `BOTTOM` |
+    /// | `n`        | `INFINITY` | normal     | `ZERO`     | `BOTTOM` |
+    /// | `INFINITY` | `INFINITY` | `INFINITY` | `BOTTOM`   | `BOTTOM` |
+    /// | `BOTTOM`   | `BOTTOM`   | `BOTTOM`   | `BOTTOM`   | `BOTTOM` |
     fn div(&self, other: &Self) -> Self {
         self.mul(&other.inv())
     }
+
+    /// `sub` for use with `?`: `Err(WheelError::Undefined)` when the difference is `BOTTOM`.
+    fn checked_sub(&self, other: &Self) -> Result<Self, WheelError> {
+        let difference = self.sub(other);
+        if difference == Self::BOTTOM { Err(WheelError::Undefined) } else { Ok(difference) }
+    }
+
+    /// `div` for use with `?`: `Err(WheelError::Undefined)` when the quotient is `BOTTOM`.
+    /// Dividing a nonzero value by `ZERO` is `Ok(INFINITY)`.
+    fn checked_div(&self, other: &Self) -> Result<Self, WheelError> {
+        let quotient = self.div(other);
+        if quotient == Self::BOTTOM { Err(WheelError::Undefined) } else { Ok(quotient) }
+    }
+
+    /// `-1 / x`. Maps `ZERO` to `INFINITY` and `INFINITY` to `ZERO`.
+    fn neg_inv(&self) -> Self {
+        self.inv().neg()
+    }
+
+    /// `1 - x`. `INFINITY` stays `INFINITY`.
+    fn one_minus(&self) -> Self {
+        Self::ONE.sub(self)
+    }
+
+    /// `1 / (1 - x)`. `ONE` maps to `INFINITY` and `INFINITY` to `ZERO`.
+    fn inv_one_minus(&self) -> Self {
+        self.one_minus().inv()
+    }
+
+    /// Integer power by repeated squaring. Negative exponents raise `self.inv()`.
+    /// `x.pow(0)` is `ONE` for every `x`.
+    fn pow(&self, exp: i32) -> Self {
+        let mut base = if exp < 0 { self.inv() } else { self.mul(&Self::ONE) };
+        let mut exp = exp.unsigned_abs();
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(&base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul(&base);
+            }
+        }
+        result
+    }
+
+    /// `self * 2^k`. The float wheels only adjust the exponent (see `ldexp`),
+    /// and `FractionWheel` doubles or halves step by step, so both are exact
+    /// unless the result leaves the representable range.
+    /// `ZERO`, `INFINITY` and `BOTTOM` are unchanged.
+    fn scale_by_pow2(&self, k: i32) -> Self {
+        self.mul(&Self::ONE.add(&Self::ONE).pow(k))
+    }
+
+    /// Linear interpolation `self + (other - self) * t`.
+    /// With an `INFINITY` endpoint, the result is `INFINITY` for a nonzero
+    /// finite `t`, and `BOTTOM` at `t = 0` (since `0 * INFINITY` is `BOTTOM`).
+    /// A `BOTTOM` endpoint or `t` gives `BOTTOM`.
+    fn lerp(&self, other: &Self, t: &Self) -> Self {
+        self.add(&other.sub(self).mul(t))
+    }
 }
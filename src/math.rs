@@ -0,0 +1,168 @@
+//! Elementary functions not available in `core`.
+// The atan constants are kept exactly as written in fdlibm.
+#![allow(clippy::excessive_precision, clippy::approx_constant)]
+
+// `atan` and its constants are ported from fdlibm's s_atan.c, which carries
+// the following notice:
+//
+// ====================================================
+// Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+//
+// Developed at SunPro, a Sun Microsystems, Inc. business.
+// Permission to use, copy, modify, and distribute this
+// software is freely granted, provided that this notice
+// is preserved.
+// ====================================================
+
+const ATAN_HI: [f64; 4] = [
+    4.63647609000806093515e-01, // atan(0.5) hi
+    7.85398163397448278999e-01, // atan(1.0) hi
+    9.82793723247329054082e-01, // atan(1.5) hi
+    1.57079632679489655800e+00, // atan(inf) hi
+];
+
+const ATAN_LO: [f64; 4] = [
+    2.26987774529616870924e-17, // atan(0.5) lo
+    3.06161699786838301793e-17, // atan(1.0) lo
+    1.39033110312309984516e-17, // atan(1.5) lo
+    6.12323399573676603587e-17, // atan(inf) lo
+];
+
+const AT: [f64; 11] = [
+    3.33333333333329318027e-01,
+    -1.99999999998764832476e-01,
+    1.42857142725034663711e-01,
+    -1.11111104054623557880e-01,
+    9.09088713343650656196e-02,
+    -7.69187620504482999495e-02,
+    6.66107313738753120669e-02,
+    -5.83357013379057348645e-02,
+    4.97687799461593236017e-02,
+    -3.65315727442169155270e-02,
+    1.62858201153657823623e-02,
+];
+
+/// Arctangent of a finite value, ported from fdlibm.
+pub(crate) fn atan(x: f64) -> f64 {
+    let negative = x.is_sign_negative();
+    let mut x = x.abs();
+    if x >= 7.378697629483821e19 {
+        // |x| >= 2^66
+        let z = ATAN_HI[3] + ATAN_LO[3];
+        return if negative { -z } else { z };
+    }
+    let id = if x < 0.4375 {
+        if x < 7.450580596923828e-9 {
+            // |x| < 2^-27
+            return if negative { -x } else { x };
+        }
+        None
+    } else if x < 1.1875 {
+        if x < 0.6875 {
+            x = (2.0 * x - 1.0) / (2.0 + x);
+            Some(0)
+        } else {
+            x = (x - 1.0) / (x + 1.0);
+            Some(1)
+        }
+    } else if x < 2.4375 {
+        x = (x - 1.5) / (1.0 + 1.5 * x);
+        Some(2)
+    } else {
+        x = -1.0 / x;
+        Some(3)
+    };
+    let z = x * x;
+    let w = z * z;
+    let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+    let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+    let z = match id {
+        None => return if negative { -(x - x * (s1 + s2)) } else { x - x * (s1 + s2) },
+        Some(id) => ATAN_HI[id] - ((x * (s1 + s2) - ATAN_LO[id]) - x),
+    };
+    if negative { -z } else { z }
+}
+
+/// Square root of a positive finite value, correctly rounded.
+/// Works on the integer significand, so subnormals need no special care.
+pub(crate) fn sqrt(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let biased_exponent = (bits >> 52) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    // x = significand * 2^exponent
+    let (mut significand, mut exponent) = if biased_exponent == 0 {
+        (fraction as u128, -1074)
+    } else {
+        ((fraction | 1 << 52) as u128, biased_exponent - 1075)
+    };
+    if exponent % 2 != 0 {
+        significand <<= 1;
+        exponent -= 1;
+    }
+    // Widen to about 110 bits so the root has a couple of bits beyond the 53 kept.
+    let shift = (significand.leading_zeros() - 17) & !1;
+    significand <<= shift;
+    exponent -= shift as i32;
+    let root = isqrt(significand);
+    let sticky = root * root != significand;
+
+    let dropped = 128 - root.leading_zeros() - 53;
+    let mut kept = (root >> dropped) as u64;
+    let half = 1u128 << (dropped - 1);
+    let rest = root & ((1u128 << dropped) - 1);
+    if rest > half || (rest == half && (sticky || kept & 1 == 1)) {
+        kept += 1;
+    }
+    let mut result_exponent = exponent / 2 + dropped as i32;
+    if kept == 1 << 53 {
+        kept >>= 1;
+        result_exponent += 1;
+    }
+    f64::from_bits(((result_exponent + 1075) as u64) << 52 | (kept & ((1 << 52) - 1)))
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: u128) -> u128 {
+    let mut x = 1u128 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// `a * b + c`, rounded once with `std` and twice without it.
+pub(crate) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+    #[cfg(feature = "std")]
+    return a.mul_add(b, c);
+    #[cfg(not(feature = "std"))]
+    return a * b + c;
+}
+
+/// `a * b` as an unevaluated sum `p + e` with `p = fl(a * b)`, exact as long as
+/// nothing underflows. Without `std` this uses Dekker's split instead of a
+/// fused multiply-add, which loses the error term for operands above 2^995.
+pub(crate) fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    #[cfg(feature = "std")]
+    let e = a.mul_add(b, -p);
+    #[cfg(not(feature = "std"))]
+    let e = if a.abs() < 1e299 && b.abs() < 1e299 {
+        let (a_hi, a_lo) = split(a);
+        let (b_hi, b_lo) = split(b);
+        ((a_hi * b_hi - p) + a_hi * b_lo + a_lo * b_hi) + a_lo * b_lo
+    } else {
+        0.0
+    };
+    (p, e)
+}
+
+/// Splits `x` into two halves of 26 significant bits each.
+#[cfg(not(feature = "std"))]
+fn split(x: f64) -> (f64, f64) {
+    let c = 134217729.0 * x; // 2^27 + 1
+    let hi = c - (c - x);
+    (hi, x - hi)
+}
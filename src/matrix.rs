@@ -0,0 +1,84 @@
+//! 2×2 matrices of wheel numbers.
+
+use crate::Wheel;
+
+use core::array;
+use core::ops::Mul;
+
+/// A 2×2 matrix `[[a, b], [c, d]]`, stored by rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mat2<W: Wheel>(pub [[W; 2]; 2]);
+
+impl<W: Wheel> Mat2<W> {
+    pub fn new(rows: [[W; 2]; 2]) -> Self {
+        Mat2(rows)
+    }
+
+    /// `a * d - b * c`.
+    pub fn det(&self) -> W {
+        let [[a, b], [c, d]] = &self.0;
+        a.mul(d).sub(&b.mul(c))
+    }
+
+    /// The adjugate `[[d, -b], [-c, a]]` divided by the determinant.
+    /// A singular matrix does not panic: each nonzero entry of the adjugate
+    /// becomes `INFINITY` and each zero entry becomes `BOTTOM`.
+    pub fn inverse(&self) -> Self {
+        let [[a, b], [c, d]] = &self.0;
+        let det = self.det();
+        Mat2([
+            [d.div(&det), b.neg().div(&det)],
+            [c.neg().div(&det), a.div(&det)],
+        ])
+    }
+}
+
+impl<W: Wheel> Mul for Mat2<W> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Mat2(array::from_fn(|i| {
+            array::from_fn(|j| self.0[i][0].mul(&other.0[0][j]).add(&self.0[i][1].mul(&other.0[1][j])))
+        }))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{w64, qw32};
+
+    fn mat(rows: [[i32; 2]; 2]) -> Mat2<qw32> {
+        Mat2(rows.map(|row| row.map(qw32::from)))
+    }
+
+    #[test]
+    fn det() {
+        assert_eq!(mat([[1, 2], [3, 4]]).det(), qw32::from(-2));
+        assert_eq!(mat([[1, 2], [2, 4]]).det(), qw32::ZERO);
+        assert_eq!(Mat2([[w64::INFINITY, w64::ONE], [w64::ONE, w64::ONE]]).det(), w64::INFINITY);
+    }
+
+    #[test]
+    fn inverse() {
+        let m = mat([[1, 2], [3, 4]]);
+        let inverse = m.inverse();
+        assert_eq!(inverse, Mat2([
+            [qw32::from(-2), qw32::ONE],
+            [qw32::new(3, 2), qw32::new(-1, 2)],
+        ]));
+        assert_eq!(m * inverse, mat([[1, 0], [0, 1]]));
+        assert_eq!(inverse * m, mat([[1, 0], [0, 1]]));
+    }
+
+    #[test]
+    fn singular_inverse() {
+        assert_eq!(mat([[1, 2], [2, 4]]).inverse(), Mat2([[qw32::INFINITY; 2]; 2]));
+        assert_eq!(mat([[1, 0], [0, 0]]).inverse(), Mat2([
+            [qw32::BOTTOM, qw32::BOTTOM],
+            [qw32::BOTTOM, qw32::INFINITY],
+        ]));
+        assert_eq!(mat([[0, 0], [0, 0]]).inverse(), Mat2([[qw32::BOTTOM; 2]; 2]));
+    }
+}
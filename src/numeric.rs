@@ -0,0 +1,373 @@
+//! Numerical helpers generic over any `Wheel`.
+
+use crate::{Wheel, WheelClass};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// `a + b` on borrowed values, without cloning.
+pub fn add_ref<W: Wheel>(a: &W, b: &W) -> W {
+    a.add(b)
+}
+
+/// `a - b` on borrowed values.
+pub fn sub_ref<W: Wheel>(a: &W, b: &W) -> W {
+    a.sub(b)
+}
+
+/// `a * b` on borrowed values.
+pub fn mul_ref<W: Wheel>(a: &W, b: &W) -> W {
+    a.mul(b)
+}
+
+/// `a / b` on borrowed values.
+pub fn div_ref<W: Wheel>(a: &W, b: &W) -> W {
+    a.div(b)
+}
+
+/// Newton's method: repeats `x - f(x) / df(x)` for `steps` iterations.
+/// A zero derivative makes the step `INFINITY` instead of panicking;
+/// iteration stops early once the value becomes `BOTTOM`.
+pub fn newton<W: Wheel, F, DF>(f: F, df: DF, x0: W, steps: u32) -> W
+where
+    F: Fn(&W) -> W,
+    DF: Fn(&W) -> W,
+{
+    let mut x = x0;
+    for _ in 0..steps {
+        if x == W::BOTTOM {
+            break;
+        }
+        x = x.sub(&f(&x).div(&df(&x)));
+    }
+    x
+}
+
+/// Evaluates the polynomial `c[0] + c[1] x + c[2] x² + ...` by Horner's rule.
+/// Starting from the leading coefficient (rather than `ZERO * x`) keeps
+/// `x = INFINITY` from turning every polynomial into `BOTTOM`.
+pub fn eval_poly<W: Wheel>(coefficients: &[W], x: &W) -> W {
+    let Some((leading, rest)) = coefficients.split_last() else {
+        return W::ZERO;
+    };
+    rest.iter().rev().fold(leading.add(&W::ZERO), |acc, c| acc.mul(x).add(c))
+}
+
+/// Evaluates the truncated power series `Σ cₖ xᵏ`, as `eval_poly` does.
+/// At `x = INFINITY` a nonzero term of positive degree makes the sum `INFINITY`;
+/// a `BOTTOM` coefficient or argument makes it `BOTTOM`.
+pub fn eval_series<W: Wheel>(coeffs: &[W], x: W) -> W {
+    eval_poly(coeffs, &x)
+}
+
+/// Evaluates the rational function `num(x) / den(x)` (coefficients in ascending powers).
+/// A pole yields `INFINITY` and a removable `0 / 0` yields `BOTTOM`, per wheel division.
+pub fn eval_rational<W: Wheel>(num: &[W], den: &[W], x: W) -> W {
+    eval_poly(num, &x).div(&eval_poly(den, &x))
+}
+
+/// `Σ wᵢ vᵢ / Σ wᵢ`, by wheel operations throughout. Zero total weight gives
+/// `BOTTOM` (or `INFINITY` if the weighted sum is nonzero). A single `INFINITY`
+/// weight dominates, giving its value, unless some weight is `BOTTOM`; two or
+/// more `INFINITY` weights give `BOTTOM`, as `∞ + ∞` does.
+///
+/// # Panics
+///
+/// If `values` and `weights` differ in length.
+pub fn weighted_average<W: Wheel>(values: &[W], weights: &[W]) -> W {
+    assert_eq!(values.len(), weights.len(), "values and weights differ in length");
+    let mut infinite = weights.iter().zip(values).filter(|(w, _)| w.classify() == WheelClass::Infinity);
+    if let (Some((_, value)), None) = (infinite.next(), infinite.next()) {
+        if !weights.iter().any(|w| w.classify() == WheelClass::Bottom) {
+            return value.add(&W::ZERO);
+        }
+    }
+    let total = weights.iter().fold(W::ZERO, |acc, w| acc.add(w));
+    W::dot(weights, values).div(&total)
+}
+
+/// The discrete convolution of `a` and `b`, i.e. the coefficients of the
+/// product polynomial. A zero coefficient meeting `INFINITY` gives `BOTTOM`
+/// in that position, per wheel multiplication.
+#[cfg(feature = "alloc")]
+pub fn convolve<W: Wheel>(a: &[W], b: &[W]) -> Vec<W> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result: Vec<W> = (0..a.len() + b.len() - 1).map(|_| W::ZERO).collect();
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] = result[i + j].add(&x.mul(y));
+        }
+    }
+    result
+}
+
+/// Folds `iter` with `op` starting from `identity`, returning `BOTTOM` as soon
+/// as the accumulator becomes `BOTTOM` without consuming the rest.
+/// This is only the plain fold when `op` absorbs `BOTTOM` as `add` and `mul` do.
+pub fn accumulate<W, I, F>(iter: I, identity: W, op: F) -> W
+where
+    W: Wheel,
+    I: IntoIterator<Item = W>,
+    F: Fn(&W, &W) -> W,
+{
+    let mut acc = identity;
+    if acc == W::BOTTOM {
+        return acc;
+    }
+    for x in iter {
+        acc = op(&acc, &x);
+        if acc == W::BOTTOM {
+            break;
+        }
+    }
+    acc
+}
+
+/// The prefix sums `[x0, x0 + x1, x0 + x1 + x2, …]`. `BOTTOM` absorbs under
+/// addition, so once an entry is `BOTTOM` every later entry is too.
+#[cfg(feature = "alloc")]
+pub fn cumulative_sum<W: Wheel>(values: &[W]) -> Vec<W> {
+    scan(values, |acc, x| acc.add(x))
+}
+
+/// The prefix products `[x0, x0 · x1, x0 · x1 · x2, …]`. As with
+/// `cumulative_sum`, every entry from the first `BOTTOM` on is `BOTTOM`.
+#[cfg(feature = "alloc")]
+pub fn cumulative_product<W: Wheel>(values: &[W]) -> Vec<W> {
+    scan(values, |acc, x| acc.mul(x))
+}
+
+/// `[ONE, x, x², …, x^up_to]`, each entry one wheel multiply from the last,
+/// for evaluating many polynomials at the same point. The first entry is
+/// `ONE` whatever `x` is, as with `pow(0)`; after it, `INFINITY` stays
+/// `INFINITY` and `BOTTOM` stays `BOTTOM`.
+#[cfg(feature = "alloc")]
+pub fn power_table<W: Wheel>(x: W, up_to: usize) -> Vec<W> {
+    let mut table = Vec::with_capacity(up_to + 1);
+    table.push(W::ONE);
+    for i in 0..up_to {
+        let next = table[i].mul(&x);
+        table.push(next);
+    }
+    table
+}
+
+#[cfg(feature = "alloc")]
+fn scan<W: Wheel>(values: &[W], op: impl Fn(&W, &W) -> W) -> Vec<W> {
+    let mut result: Vec<W> = Vec::with_capacity(values.len());
+    for x in values {
+        let next = match result.last() {
+            Some(acc) => op(acc, x),
+            None => x.add(&W::ZERO),
+        };
+        result.push(next);
+    }
+    result
+}
+
+/// Counts the values in each category, indexed by `WheelClass::code`:
+/// `[zero, normal, infinity, bottom]` (the order of `Wheel::specials`).
+pub fn class_counts<W: Wheel>(values: &[W]) -> [usize; 4] {
+    let mut counts = [0; 4];
+    for value in values {
+        counts[usize::from(value.classify().code())] += 1;
+    }
+    counts
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{w64, qw64};
+
+    /// Works for any wheel without `Clone`, only borrowing the operands.
+    fn sum_of_products<W: Wheel>(a: &[W], b: &[W]) -> W {
+        a.iter().zip(b).fold(W::ZERO, |acc, (x, y)| add_ref(&acc, &mul_ref(x, y)))
+    }
+
+    #[test]
+    fn ref_arithmetic() {
+        let a = [qw64::new(1, 2), qw64::new(2, 1), qw64::new(-3, 1)];
+        let b = [qw64::new(2, 1), qw64::new(1, 4), qw64::ONE];
+        assert_eq!(sum_of_products(&a, &b), qw64::new(-3, 2));
+        assert_eq!(sub_ref(&a[0], &b[1]), qw64::new(1, 4));
+        assert_eq!(div_ref(&a[0], &b[1]), qw64::new(2, 1));
+        assert_eq!(div_ref(&a[0], &qw64::ZERO), qw64::INFINITY);
+
+        let a = [w64::new(1.5), w64::INFINITY];
+        let b = [w64::new(2.0), w64::ZERO];
+        assert_eq!(sum_of_products(&a, &b), w64::BOTTOM);
+        assert_eq!(sum_of_products(&a[..1], &b[..1]), w64::new(3.0));
+    }
+
+    #[test]
+    fn newton_sqrt2() {
+        let two = w64::new(2.0);
+        let root = newton(|x: &w64| x * x - two, |x: &w64| two * x, w64::ONE, 10);
+        assert!(root.roughly_eq(w64::new(core::f64::consts::SQRT_2)));
+
+        let two = qw64::new(2, 1);
+        let root = newton(|x: &qw64| x * x - two, |x: &qw64| two * x, qw64::ONE, 3);
+        assert_eq!(root, qw64::new(577, 408));
+    }
+
+    #[test]
+    fn newton_pole() {
+        let two = w64::new(2.0);
+        let f = |x: &w64| x * x - two;
+        let df = |x: &w64| two * x;
+        assert_eq!(newton(f, df, w64::ZERO, 1), w64::INFINITY);
+        assert_eq!(newton(f, df, w64::ZERO, 10), w64::BOTTOM);
+    }
+
+    #[test]
+    fn class_counts_mixed() {
+        let values = [
+            w64::ONE, w64::ZERO, w64::new(-2.5), w64::BOTTOM,
+            w64::INFINITY, w64::ONE / w64::ZERO, w64::ZERO / w64::ZERO, w64::new(1e-310),
+        ];
+        assert_eq!(class_counts(&values), [1, 3, 2, 2]);
+        assert_eq!(class_counts(&qw64::specials()), [1, 1, 1, 1]);
+        assert_eq!(class_counts::<qw64>(&[]), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn eval_rational_poles() {
+        let x_minus_one = [qw64::new(-1, 1), qw64::ONE];
+        let p = eval_poly(&x_minus_one, &qw64::ONE);
+        assert_eq!(eval_rational(&x_minus_one, &x_minus_one, qw64::ONE), qw64::ONE + qw64::ZERO * p / p);
+        assert_eq!(eval_rational(&x_minus_one, &x_minus_one, qw64::ONE), qw64::BOTTOM);
+        assert_eq!(eval_rational(&x_minus_one, &x_minus_one, qw64::new(3, 1)), qw64::ONE);
+        assert_eq!(eval_rational(&[qw64::ONE], &x_minus_one, qw64::ONE), qw64::INFINITY);
+
+        // (x² + 1) / (2x)
+        let num = [w64::ONE, w64::ZERO, w64::ONE];
+        let den = [w64::ZERO, w64::new(2.0)];
+        assert_eq!(eval_rational(&num, &den, w64::new(2.0)), w64::new(1.25));
+        assert_eq!(eval_rational(&num, &den, w64::ZERO), w64::INFINITY);
+        assert_eq!(eval_rational(&num, &den, w64::INFINITY), w64::BOTTOM);
+        assert_eq!(eval_poly(&num, &w64::INFINITY), w64::INFINITY);
+        assert_eq!(eval_poly::<w64>(&[], &w64::ONE), w64::ZERO);
+    }
+
+    #[test]
+    fn eval_series_manual() {
+        // 1 + x + x²/2 + x³/6
+        let coeffs = [qw64::ONE, qw64::ONE, qw64::new(1, 2), qw64::new(1, 6)];
+        let x = qw64::new(1, 2);
+        let manual = qw64::ONE + x + x * x / qw64::new(2, 1) + x * x * x / qw64::new(6, 1);
+        assert_eq!(eval_series(&coeffs, x), manual);
+        assert_eq!(eval_series(&coeffs, qw64::ZERO), qw64::ONE);
+        assert_eq!(eval_series(&coeffs, qw64::INFINITY), qw64::INFINITY);
+        assert_eq!(eval_series(&coeffs[..1], qw64::INFINITY), qw64::ONE);
+        assert_eq!(eval_series(&coeffs, qw64::BOTTOM), qw64::BOTTOM);
+        assert_eq!(eval_series(&[qw64::ONE, qw64::BOTTOM], qw64::ONE), qw64::BOTTOM);
+
+        let coeffs = [w64::new(2.0), w64::new(-3.0), w64::ONE];
+        let x = w64::new(1.5);
+        assert_eq!(eval_series(&coeffs, x), w64::new(2.0) - w64::new(3.0) * x + x * x);
+        assert_eq!(eval_series(&coeffs, w64::INFINITY), w64::INFINITY);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn convolve_sequences() {
+        // (1 + 2x)(3 - x + x²)
+        let a = [qw64::ONE, qw64::new(2, 1)];
+        let b = [qw64::new(3, 1), qw64::new(-1, 1), qw64::ONE];
+        let manual = [
+            a[0] * b[0],
+            a[0] * b[1] + a[1] * b[0],
+            a[0] * b[2] + a[1] * b[1],
+            a[1] * b[2],
+        ];
+        assert_eq!(convolve(&a, &b), manual);
+        let x = qw64::new(1, 3);
+        assert_eq!(eval_poly(&convolve(&a, &b), &x), eval_poly(&a, &x) * eval_poly(&b, &x));
+
+        let a = [w64::ONE, w64::ZERO, w64::new(2.0)];
+        let b = [w64::INFINITY, w64::ONE];
+        assert_eq!(convolve(&a, &b), [w64::INFINITY, w64::BOTTOM, w64::INFINITY, w64::new(2.0)]);
+        assert_eq!(convolve::<w64>(&[], &b), []);
+    }
+
+    #[test]
+    fn accumulate_custom_op() {
+        // Resistors in parallel: 1 / (1/a + 1/b), with identity INFINITY.
+        let reciprocal_add = |a: &qw64, b: &qw64| a.inv().add(&b.inv()).inv();
+        let resistors = [qw64::new(2, 1), qw64::new(3, 1), qw64::new(6, 1)];
+        assert_eq!(accumulate(resistors, qw64::INFINITY, reciprocal_add), qw64::ONE);
+        assert_eq!(accumulate([], qw64::INFINITY, reciprocal_add), qw64::INFINITY);
+        assert_eq!(accumulate(resistors, qw64::ZERO, |a, b| a.add(b)), qw64::new(11, 1));
+    }
+
+    #[test]
+    fn accumulate_stops_at_bottom() {
+        let seen = core::cell::Cell::new(0);
+        let values = [w64::ONE, w64::INFINITY, w64::INFINITY, w64::ONE, w64::ONE];
+        let result = accumulate(values.iter().inspect(|_| seen.set(seen.get() + 1)).copied(), w64::ZERO, |a, b| a.add(b));
+        assert_eq!(result, w64::BOTTOM);
+        // INFINITY + INFINITY is BOTTOM at the third value; nothing after it is pulled.
+        assert_eq!(seen.get(), 3);
+        assert_eq!(accumulate(values, w64::BOTTOM, |_, _| w64::ONE), w64::BOTTOM);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cumulative() {
+        let values = [qw64::new(1, 2), qw64::new(4, 1), qw64::ZERO, qw64::INFINITY, qw64::new(3, 1)];
+        assert_eq!(cumulative_sum(&values), [
+            qw64::new(1, 2), qw64::new(9, 2), qw64::new(9, 2), qw64::INFINITY, qw64::INFINITY,
+        ]);
+        assert_eq!(cumulative_product(&values), [
+            qw64::new(1, 2), qw64::new(2, 1), qw64::ZERO, qw64::BOTTOM, qw64::BOTTOM,
+        ]);
+        let values = [w64::new(2.0), w64::BOTTOM, w64::new(3.0)];
+        assert_eq!(cumulative_sum(&values), [w64::new(2.0), w64::BOTTOM, w64::BOTTOM]);
+        assert_eq!(cumulative_product(&values), [w64::new(2.0), w64::BOTTOM, w64::BOTTOM]);
+        assert_eq!(cumulative_product::<w64>(&[]), []);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn power_table() {
+        let x = qw64::new(-2, 3);
+        let table = super::power_table(x, 4);
+        assert_eq!(table.len(), 5);
+        for (k, entry) in table.iter().enumerate() {
+            assert_eq!(*entry, x.pow(k as i32));
+        }
+        let x = w64::new(1.1);
+        assert_eq!(super::power_table(x, 4)[3], x.pow(3));
+        assert_eq!(super::power_table(w64::INFINITY, 2), [w64::ONE, w64::INFINITY, w64::INFINITY]);
+        assert_eq!(super::power_table(qw64::BOTTOM, 2), [qw64::ONE, qw64::BOTTOM, qw64::BOTTOM]);
+        assert_eq!(super::power_table(qw64::ZERO, 0), [qw64::ONE]);
+    }
+
+    #[test]
+    fn weighted_average() {
+        let values = [qw64::new(1, 1), qw64::new(4, 1), qw64::new(-2, 1)];
+        let weights = [qw64::new(1, 2), qw64::new(1, 4), qw64::new(1, 4)];
+        assert_eq!(super::weighted_average(&values, &weights), qw64::new(1, 1));
+        let values = [w64::new(2.0), w64::new(5.0)];
+        assert_eq!(super::weighted_average(&values, &[w64::new(2.0), w64::ONE]), w64::new(3.0));
+        assert_eq!(super::weighted_average(&values, &[w64::ZERO, w64::ZERO]), w64::BOTTOM);
+        assert_eq!(super::weighted_average::<qw64>(&[], &[]), qw64::BOTTOM);
+        assert_eq!(super::weighted_average(&values, &[w64::INFINITY, w64::ONE]), w64::new(2.0));
+        assert_eq!(super::weighted_average(&values, &[w64::ZERO, w64::INFINITY]), w64::new(5.0));
+        assert_eq!(super::weighted_average(&values, &[w64::INFINITY, w64::INFINITY]), w64::BOTTOM);
+        assert_eq!(super::weighted_average(&values, &[w64::INFINITY, w64::BOTTOM]), w64::BOTTOM);
+        let values = [qw64::new(1, 3), qw64::new(7, 1), qw64::new(-2, 1)];
+        let weights = [qw64::ONE, qw64::INFINITY, qw64::new(5, 1)];
+        assert_eq!(super::weighted_average(&values, &weights), qw64::new(7, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_average_length_mismatch() {
+        super::weighted_average(&[w64::ONE], &[]);
+    }
+}
@@ -0,0 +1,119 @@
+//! Small fixed-size vectors of wheel numbers.
+
+use crate::Wheel;
+
+use core::array;
+use core::ops::{Add, Sub, Mul, Div, Neg};
+
+/// A fixed-size vector with elementwise operations.
+/// Dividing by a zero component yields `INFINITY` in that component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WheelVec<W: Wheel, const N: usize>(pub [W; N]);
+
+impl<W: Wheel, const N: usize> WheelVec<W, N> {
+    pub fn new(components: [W; N]) -> Self {
+        WheelVec(components)
+    }
+
+    /// Multiplies every component by `scalar`.
+    pub fn mul_scalar(&self, scalar: &W) -> Self {
+        WheelVec(array::from_fn(|i| self.0[i].mul(scalar)))
+    }
+
+    /// Sum of the elementwise products, see `Wheel::dot`.
+    pub fn dot(&self, other: &Self) -> W {
+        W::dot(&self.0, &other.0)
+    }
+}
+
+impl<W: Wheel, const N: usize> Add for WheelVec<W, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        WheelVec(array::from_fn(|i| self.0[i].add(&other.0[i])))
+    }
+}
+
+impl<W: Wheel, const N: usize> Sub for WheelVec<W, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        WheelVec(array::from_fn(|i| self.0[i].sub(&other.0[i])))
+    }
+}
+
+impl<W: Wheel, const N: usize> Mul for WheelVec<W, N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        WheelVec(array::from_fn(|i| self.0[i].mul(&other.0[i])))
+    }
+}
+
+impl<W: Wheel, const N: usize> Div for WheelVec<W, N> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        WheelVec(array::from_fn(|i| self.0[i].div(&other.0[i])))
+    }
+}
+
+impl<W: Wheel, const N: usize> Neg for WheelVec<W, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        WheelVec(array::from_fn(|i| self.0[i].neg()))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{w64, qw32};
+
+    #[test]
+    fn elementwise() {
+        let a = WheelVec([w64::new(1.0), w64::new(2.0), w64::new(3.0)]);
+        let b = WheelVec([w64::new(4.0), w64::ZERO, w64::new(-1.0)]);
+        assert_eq!(a + b, WheelVec([w64::new(5.0), w64::new(2.0), w64::new(2.0)]));
+        assert_eq!(a - b, WheelVec([w64::new(-3.0), w64::new(2.0), w64::new(4.0)]));
+        assert_eq!(a * b, WheelVec([w64::new(4.0), w64::ZERO, w64::new(-3.0)]));
+        assert_eq!(a / b, WheelVec([w64::new(0.25), w64::INFINITY, w64::new(-3.0)]));
+        assert_eq!(-a, WheelVec([w64::new(-1.0), w64::new(-2.0), w64::new(-3.0)]));
+        assert_eq!(a.mul_scalar(&w64::new(2.0)), WheelVec([w64::new(2.0), w64::new(4.0), w64::new(6.0)]));
+    }
+
+    #[test]
+    fn dot_product() {
+        let a = WheelVec([qw32::new(1, 2), qw32::new(2, 1)]);
+        let b = WheelVec([qw32::new(2, 1), qw32::new(1, 4)]);
+        assert_eq!(a.dot(&b), qw32::new(3, 2));
+
+        let c = WheelVec([qw32::new(1, 2), qw32::INFINITY]);
+        assert_eq!(a.dot(&c), qw32::INFINITY);
+
+        let d = WheelVec([qw32::ZERO, qw32::ONE]);
+        let e = WheelVec([qw32::INFINITY, qw32::ONE]);
+        assert_eq!(d.dot(&e), qw32::BOTTOM);
+    }
+
+    #[test]
+    fn dot_product_exact_for_fractions() {
+        let a = WheelVec([qw32::new(1, 3), qw32::new(1, 6), qw32::new(-1, 2)]);
+        let b = WheelVec([qw32::ONE; 3]);
+        assert_eq!(a.dot(&b), qw32::ZERO);
+
+        let big = WheelVec([w64::new(1e16), w64::ONE, w64::new(-1e16)]);
+        let ones = WheelVec([w64::ONE; 3]);
+        assert_eq!(big.dot(&ones), w64::ONE);
+    }
+
+    #[test]
+    fn sum_of_products_exact_for_fractions() {
+        let a = [qw32::new(1, 3), qw32::new(2, 5), qw32::new(-7, 4)];
+        let b = [qw32::new(3, 1), qw32::new(5, 4), qw32::new(2, 7)];
+        assert_eq!(qw32::sum_of_products(&a, &b), qw32::ONE);
+        assert_eq!(qw32::sum_of_products(&a, &b), qw32::dot(&a, &b));
+    }
+}